@@ -0,0 +1,233 @@
+Feature: FS-10 - test results as verification evidence
+    This solution must accept test results as evidence of the execution of the verification plan,
+    in the form of JUnit XML (`.xml`) or Cucumber JSON (`.json`) files passed via `--evidence`, once per file, before or after a subcommand.
+
+    An execution (a JUnit `testcase` or a Cucumber scenario) refers to an item when
+    - its group (JUnit `classname` or Cucumber feature name) is of the form `ID - title`, or
+    - its name is of the form `ID - title`, or
+    - it is tagged with the item's identifier (Cucumber tag or JUnit property named `tag`)
+
+    An execution verifies a requirement when it refers to the requirement (e.g. a scenario of the
+    requirement's feature) or to a test traced to the requirement.
+
+    For every requirement, the solution must report whether it is verified by passing executions
+    (`passed`), by failing executions (`failed`), or by none (`unverified`).
+    A requirement with at least one failing execution is `failed`.
+    Skipped executions, including Cucumber scenarios with pending or undefined steps, are not
+    executed and verify nothing.
+
+    Example: requirement verified by a passing JUnit test
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `results/junit.xml`
+            """
+            <testsuite name="unit">
+                <testcase classname="TEST-1 - Unit tests" name="it works"/>
+            </testsuite>
+            """
+        When we check it with `--evidence results/junit.xml`
+        Then we get an output containing
+            """
+            "status": "passed"
+            """
+
+    Example: requirement verified by a failing Cucumber scenario
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Acceptance tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `results/cucumber.json`
+            """
+            [{
+                "name": "FS-1 - something",
+                "elements": [{
+                    "name": "Something",
+                    "type": "scenario",
+                    "steps": [
+                        {"result": {"status": "passed"}},
+                        {"result": {"status": "failed"}}
+                    ]
+                }]
+            }]
+            """
+        And the following file `deviations.md`
+            """
+            # Deviations
+            ## DEV-1 - Something fails
+            Severity: minor
+            Status: open
+
+            ### Trace
+            * FS-1
+            """
+        When we check it with `--evidence results/cucumber.json`
+        Then we get an output containing
+            """
+            "status": "failed"
+            """
+
+    Example: Cucumber scenario with an undefined step
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Acceptance tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `results/cucumber.json`
+            """
+            [{
+                "name": "FS-1 - something",
+                "elements": [{
+                    "name": "Something",
+                    "type": "scenario",
+                    "steps": [
+                        {"result": {"status": "passed"}},
+                        {"result": {"status": "undefined"}}
+                    ]
+                }]
+            }]
+            """
+        When we check it with `--evidence results/cucumber.json`
+        Then we get an output containing
+            """
+            "status": "unverified"
+            """
+
+    Example: requirement without evidence
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `results/junit.xml`
+            """
+            <testsuite name="unit">
+                <testcase classname="unit" name="it works" />
+            </testsuite>
+            """
+        When we check it with `--evidence results/junit.xml`
+        Then we get an output containing
+            """
+            "status": "unverified"
+            """
+
+    Example: self-closing JUnit test followed by a tag of the suite
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following file `features/other.feature`
+            """
+Feature: FS-2 - something else
+    Scenario: Something else
+        When something else
+        Then something more
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+        ## TEST-2 - Acceptance tests
+        ### Trace
+        * FS-2
+            """
+        And the following file `results/junit.xml`
+            """
+            <testsuite name="unit">
+                <testcase classname="TEST-1 - Unit tests" name="it works"/>
+                <properties>
+                    <property name="tag" value="FS-2"/>
+                </properties>
+            </testsuite>
+            """
+        When we check it with `--evidence results/junit.xml`
+        Then we get an output containing
+            """
+            "status": "unverified"
+            """
+
+    Example: evidence in an unknown format
+        Given the following file `results/report.txt`
+            """
+            all tests passed
+            """
+        When we check it with `--evidence results/report.txt`
+        Then we get an error containing
+            """
+            Evidence must be JUnit XML (.xml) or Cucumber JSON (.json) files
+            """
+
+    Example: evidence before a subcommand
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `results/junit.xml`
+            """
+            <testsuite name="unit">
+                <testcase classname="TEST-1 - Unit tests" name="it works"/>
+            </testsuite>
+            """
+        And the following file `results/cucumber.json`
+            """
+            []
+            """
+        When we check it with `--evidence results/junit.xml --evidence results/cucumber.json graph --format dot`
+        Then we get an output containing
+            """
+            "TEST-1" -> "FS-1";
+            """
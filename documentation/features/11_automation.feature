@@ -0,0 +1,127 @@
+Feature: FS-11 - automation of scenarios
+    This solution must report which scenarios of the requirements are automated, by matching
+    each of their steps against the step definitions declared in source files passed via `--steps`
+    (files or directories).
+
+    The following step definitions must be supported:
+    - Rust's cucumber, e.g. `#[given(expr = "...")]`, `#[when(regex = r"...")]` or `#[then("...")]`
+    - behave, e.g. `@given("...")`
+    - cucumber-js, e.g. `Given('...')`
+
+    Steps that match no step definition must be reported as undefined, and each scenario must be
+    classified as `automated` (every step matches), `partially automated` (some steps match)
+    or `manual` (no step matches).
+
+    Example: scenario automated by Rust's cucumber
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        Given 2 users
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Acceptance tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `tests/steps.rs`
+            """
+            #[given(expr = "{int} users")]
+            fn users(w: &mut World, users: usize) {}
+
+            #[when("something")]
+            fn something(w: &mut World) {}
+
+            #[then(regex = r"^something (else|more)$")]
+            fn something_else(w: &mut World) {}
+            """
+        When we check it with `automation --steps tests`
+        Then we get an output containing
+            """
+            "automation": "automated"
+            """
+
+    Example: scenario partially automated by behave
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        Given 2 users
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Acceptance tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `steps/steps.py`
+            """
+            @given("{count:d} users")
+            def step_impl(context, count):
+                pass
+            """
+        When we check it with `automation --steps steps`
+        Then we get an output containing
+            """
+            "automation": "partially automated"
+            """
+
+    Example: undefined steps are reported
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        Given 2 users
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Acceptance tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `steps/steps.js`
+            """
+            Given('{int} users', function (count) {});
+            When('something', function () {});
+            """
+        When we check it with `automation --steps steps`
+        Then we get an output containing
+            """
+            "Then something else"
+            """
+
+    Example: scenario without step definitions is manual
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        Given 2 users
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Acceptance tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `steps/steps.js`
+            """
+            Given('a cat', function () {});
+            """
+        When we check it with `automation --steps steps`
+        Then we get an output containing
+            """
+            "automation": "manual"
+            """
@@ -0,0 +1,199 @@
+Feature: FS-12 - executed test records of manual scenarios
+    Every scenario that cannot be automated must be tagged `@manual`, and every manual
+    scenario must have at least one executed test record.
+
+    Executed test records are markdown files in a directory named `test_records`,
+    starting with a title of the form `# TR-<id> - <title>` and containing
+    - a line `Requirement: <id>` with the requirement of the executed scenario
+    - a line `Scenario: <name>` with the name of the executed scenario
+    - a line `Test: <id>` with the test, in the verification plan, traced to the requirement
+    - a line `Tester: <name>` with who executed the test
+    - a line `Date: <YYYY-MM-DD>` with when the test was executed
+    - a line `Environment: <description>` with where the test was executed
+    - a table with the columns `Step | Expected | Actual | Pass`, with one row per executed step
+      and whether it passed (`yes` or `no`); other tables, e.g. of the equipment, are ignored
+    - a line `Signature: <signature>` with the signature of the tester
+
+    Executed test records are part of the JSON output of this solution.
+
+    Example: manual scenario with an executed test record
+        Given the following feature
+            """
+Feature: FS-1 - something
+    @manual
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Manual tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `test_records/TR-1.md`
+            """
+            # TR-1 - Something
+
+            Requirement: FS-1
+            Scenario: Something
+            Test: TEST-1
+            Tester: Jane Doe
+            Date: 2023-05-02
+            Environment: UAT, version 1.2.0
+
+            | Step | Expected | Actual | Pass |
+            |------|----------|--------|------|
+            | something | something else | something else | yes |
+
+            Signature: Jane Doe
+            """
+        When we check it
+        Then we get an output containing
+            """
+            "tester": "Jane Doe"
+            """
+
+    Example: executed test record with other tables
+        Given the following feature
+            """
+Feature: FS-1 - something
+    @manual
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Manual tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `test_records/TR-1.md`
+            """
+            # TR-1 - Something
+
+            Requirement: FS-1
+            Scenario: Something
+            Test: TEST-1
+            Tester: Jane Doe
+            Date: 2023-05-02
+            Environment: UAT, version 1.2.0
+
+            | Step | Expected | Actual | Pass |
+            |------|----------|--------|------|
+            | something | something else | something else | yes |
+
+            | Equipment | Serial number |
+            |-----------|---------------|
+            | Balance | B-1234 |
+
+            Signature: Jane Doe
+            """
+        When we check it
+        Then we get an output containing
+            """
+            "actual": "something else",
+            """
+
+    Example: manual scenario without an executed test record
+        Given the following feature
+            """
+Feature: FS-1 - something
+    @manual
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Manual tests
+        ### Trace
+        * FS-1
+            """
+        When we check it
+        Then we get an error containing
+            """
+            Manual scenarios must have an executed test record, but "Something" of FS-1 has none
+            """
+
+    Example: executed test record without a tester
+        Given the following feature
+            """
+Feature: FS-1 - something
+    @manual
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Manual tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `test_records/TR-1.md`
+            """
+            # TR-1 - Something
+
+            Requirement: FS-1
+            Scenario: Something
+            Test: TEST-1
+            Date: 2023-05-02
+            Environment: UAT, version 1.2.0
+
+            | Step | Expected | Actual | Pass |
+            |------|----------|--------|------|
+            | something | something else | something else | yes |
+
+            Signature: Jane Doe
+            """
+        When we check it
+        Then we get an error containing
+            """
+            "test_records/TR-1.md" must contain a line "Tester: <value>"
+            """
+
+    Example: executed test record of a test not traced to the requirement
+        Given the following feature
+            """
+Feature: FS-1 - something
+    @manual
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Manual tests
+        ### Trace
+        * FS-1
+        ## TEST-2 - Other tests
+            """
+        And the following file `test_records/TR-1.md`
+            """
+            # TR-1 - Something
+
+            Requirement: FS-1
+            Scenario: Something
+            Test: TEST-2
+            Tester: Jane Doe
+            Date: 2023-05-02
+            Environment: UAT, version 1.2.0
+
+            | Step | Expected | Actual | Pass |
+            |------|----------|--------|------|
+            | something | something else | something else | yes |
+
+            Signature: Jane Doe
+            """
+        When we check it
+        Then we get an error containing
+            """
+            TEST-2 of TR-1 is not traced to FS-1
+            """
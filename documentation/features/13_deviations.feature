@@ -0,0 +1,142 @@
+Feature: FS-13 - deviation log
+    Deviations from the expected behavior of the software, e.g. failed tests or anomalies
+    accepted for a release, must be recorded in a deviation log.
+
+    The deviation log is optional and is documented in a single markdown file named `deviations.md`
+    starting with `# Deviations` and where each deviation is itemized by headings (h2) of the form
+    `## DEV-<id> - <title>`. Each deviation must contain
+    - a line `Severity: <severity>`, where the severity is `minor`, `major` or `critical`
+    - a line `Status: <status>`, where the status is `open`, `accepted` or `closed`
+    and may contain a subsection `### Trace` with a single list containing identifiers of
+    existing requirements, tests or executed test records.
+
+    Every failed executed test record and every failed execution passed as evidence must be traced
+    by a deviation (via the record, or any of the identifiers the execution refers to).
+
+    When checking a release (`--gate release`), open deviations with a severity above
+    `max_open_severity` of the configuration (`quality.json`, `minor` by default) must block the
+    release.
+
+    Example: a valid deviation log
+        Given the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+            """
+        And the following file `deviations.md`
+            """
+            # Deviations
+            ## DEV-1 - Unit tests are flaky
+            Severity: minor
+            Status: open
+
+            ### Trace
+            * TEST-1
+            """
+        When we check it
+        Then we get no error
+
+    Example: deviation without a severity
+        Given the following file `deviations.md`
+            """
+            # Deviations
+            ## DEV-1 - Unit tests are flaky
+            Status: open
+            """
+        When we check it
+        Then we get an error containing
+            """
+            Items of "deviations.md" must contain a line "Severity: <severity>", but DEV-1 does not
+            """
+
+    Example: failed execution not traced by a deviation
+        Given the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+            """
+        And the following file `results/junit.xml`
+            """
+            <testsuite name="unit">
+                <testcase classname="TEST-1 - Unit tests" name="it works">
+                    <failure message="it does not"/>
+                </testcase>
+            </testsuite>
+            """
+        When we check it with `--evidence results/junit.xml`
+        Then we get an error containing
+            """
+            Failed executions must be traced by a deviation
+            """
+
+    Example: failed execution traced by a deviation
+        Given the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+            """
+        And the following file `results/junit.xml`
+            """
+            <testsuite name="unit">
+                <testcase classname="TEST-1 - Unit tests" name="it works">
+                    <failure message="it does not"/>
+                </testcase>
+            </testsuite>
+            """
+        And the following file `deviations.md`
+            """
+            # Deviations
+            ## DEV-1 - Unit tests fail
+            Severity: minor
+            Status: accepted
+
+            ### Trace
+            * TEST-1
+            """
+        When we check it with `--evidence results/junit.xml`
+        Then we get no error
+
+    Example: open deviations block a release
+        Given the following file `deviations.md`
+            """
+            # Deviations
+            ## DEV-1 - Audit trail is incomplete
+            Severity: major
+            Status: open
+            """
+        When we check it with `--release`
+        Then we get an error containing
+            """
+            Releases cannot have open deviations with a severity above minor, but DEV-1 is open with severity major
+            """
+
+    Example: open deviations up to a severity are allowed in a release
+        Given the following file `deviations.md`
+            """
+            # Deviations
+            ## DEV-1 - Audit trail is incomplete
+            Severity: major
+            Status: open
+            """
+        And the following file `quality.json`
+            """
+            {"max_open_severity": "major"}
+            """
+        When we check it with `--release`
+        Then we get no error
+
+    Example: headings in code blocks of deviations
+        Given the following file `deviations.md`
+            """
+            # Deviations
+            ## DEV-1 - Audit trail is incomplete
+            Severity: minor
+            Status: open
+
+            ```
+            ## DEV-2 - Not a deviation
+            Severity: unknown
+            ```
+            """
+        When we check it
+        Then we get no error
@@ -0,0 +1,63 @@
+Feature: FS-14 - validation summary report
+    This solution must generate the validation summary report of the documentation
+    (`summary`), in markdown (default) or HTML (`--format html`), containing
+    - the intended use, as the list of requirements
+    - every document, its number of items and its version (a digest of its content)
+    - the tests, executed test records and verification status of each requirement,
+      using the evidence passed via `--evidence`, if any
+    - the open (and accepted) deviations
+    - the residual risks and the items mitigating them
+    - a conclusion and signatures to be completed
+
+    Example: summary report in markdown
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Acceptance tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `results/junit.xml`
+            """
+            <testsuite name="unit">
+                <testcase classname="TEST-1 - Acceptance tests" name="it works"/>
+            </testsuite>
+            """
+        When we check it with `summary --evidence results/junit.xml`
+        Then we get an output containing
+            """
+            | FS-1 | TEST-1 |  | passed |
+            """
+
+    Example: summary report with open deviations
+        Given the following file `deviations.md`
+            """
+            # Deviations
+            ## DEV-1 - Audit trail is incomplete
+            Severity: minor
+            Status: open
+            """
+        When we check it with `summary`
+        Then we get an output containing
+            """
+            | DEV-1 | Audit trail is incomplete | minor | open |
+            """
+
+    Example: summary report in HTML
+        Given the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Acceptance tests
+            """
+        When we check it with `summary --format html`
+        Then we get an output containing
+            """
+            <h2>Conclusion</h2>
+            """
@@ -0,0 +1,47 @@
+Feature: FS-15 - attribution of items to commits
+    When requested (`--attribution`), this solution must attribute every item of the
+    documentation to the last commit modifying it (author, email, date and commit hash),
+    using the git repository the documentation belongs to:
+    - items of documents (`## ID - title`) are attributed by `git blame` of the lines from
+      their heading until the next item
+    - requirements are attributed by `git blame` of their whole feature file
+
+    Attributions are part of the JSON output and of the validation summary report.
+
+    Example: items attributed to their last commit
+        Given the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+            """
+        And the documentation is committed to git by `Jane Doe`
+        When we check it with `--attribution`
+        Then we get an output containing
+            """
+            "author": "Jane Doe"
+            """
+
+    Example: attribution in the summary report
+        Given the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+            """
+        And the documentation is committed to git by `Jane Doe`
+        When we check it with `summary --attribution`
+        Then we get an output containing
+            """
+            | TEST-1 | Jane Doe |
+            """
+
+    Example: documentation outside a git repository
+        Given the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+            """
+        When we check it with `--attribution`
+        Then we get an error containing
+            """
+            git blame --porcelain -- verification_plan.md failed
+            """
@@ -0,0 +1,84 @@
+Feature: FS-16 - documentation at a git revision
+    When requested (`--rev <revision>`), this solution must check the documentation as of
+    a revision (e.g. a tag or a commit) of the git repository the documentation belongs to,
+    reading it from the repository's object database instead of from the file system,
+    without a checkout.
+
+    Example: documentation checked at a revision
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the documentation is committed to git by `Jane Doe`
+        And the following file `verification_plan.md`
+            """
+            # Verification plan
+            ## TEST-1 - Unit tests
+            ### Trace
+            * FS-1
+            * FS-100
+            """
+        When we check it with `--rev HEAD`
+        Then we get no error
+
+    Example: working tree checked without a revision
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the documentation is committed to git by `Jane Doe`
+        And the following file `verification_plan.md`
+            """
+            # Verification plan
+            ## TEST-1 - Unit tests
+            ### Trace
+            * FS-1
+            * FS-100
+            """
+        When we check it
+        Then we get an error containing
+            """
+            Tests can only be traced to existing risks or requirements, but TEST-1 is traced to something else
+            """
+
+    Example: revision that does not exist
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the documentation is committed to git by `Jane Doe`
+        When we check it with `--rev v1.2.0`
+        Then we get an error containing
+            """
+            Can't find revision v1.2.0 in the git repository
+            """
@@ -0,0 +1,136 @@
+Feature: FS-17 - semantic difference between revisions
+    This solution must compare the documentation between two revisions of its git
+    repository (`diff <before> <after>`), in markdown (default) or JSON (`--format json`), listing
+    - the items added, removed and modified in each document, ignoring changes of whitespace
+    - the trace added and removed
+    - the requirements whose covering tests changed
+
+    Example: added test covering a requirement
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the documentation is committed to git by `Jane Doe`
+        And the following file `verification_plan.md`
+            """
+            # Verification plan
+            ## TEST-1 - Unit tests
+            ### Trace
+            * FS-1
+
+            ## TEST-2 - Acceptance tests
+            ### Trace
+            * FS-1
+            """
+        And the documentation is committed to git by `John Doe`
+        When we check it with `diff HEAD~1 HEAD`
+        Then we get an output containing
+            """
+            | FS-1 | TEST-1 | TEST-1, TEST-2 |
+            """
+
+    Example: formatting changes are not modifications
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the documentation is committed to git by `Jane Doe`
+        And the following file `verification_plan.md`
+            """
+            # Verification plan
+
+            ## TEST-1 - Unit tests
+
+            ### Trace
+
+            *   FS-1
+            """
+        And the documentation is committed to git by `John Doe`
+        When we check it with `diff HEAD~1 HEAD`
+        Then we get an output containing
+            """
+            There are no changes.
+            """
+
+    Example: removed item
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `design_specification.md`
+            """
+            # Design specification
+            ## DS-1 - Architecture
+            """
+        And the documentation is committed to git by `Jane Doe`
+        And the following file `design_specification.md`
+            """
+            # Design specification
+            """
+        And the documentation is committed to git by `John Doe`
+        When we check it with `diff HEAD~1 HEAD`
+        Then we get an output containing
+            """
+            * Removed: DS-1
+            """
+
+    Example: difference in JSON
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the documentation is committed to git by `Jane Doe`
+        And the following file `verification_plan.md`
+            """
+            # Verification plan
+            ## TEST-1 - Unit tests
+            Runs every unit test.
+            ### Trace
+            * FS-1
+            """
+        And the documentation is committed to git by `John Doe`
+        When we check it with `diff HEAD~1 HEAD --format json`
+        Then we get an output containing
+            """
+            "verification_plan.md": {
+            """
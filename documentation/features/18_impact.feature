@@ -0,0 +1,226 @@
+Feature: FS-18 - change impact
+    This solution must report the impact of changed items (`impact <items>`), or of the items
+    changed since a revision of the git repository (`impact --since <revision>`),
+    in markdown (default) or JSON (`--format json`), by walking the trace:
+    - affected requirements are the changed ones and those that changed designs or risks trace to
+    - affected designs and risks are the changed ones, those that changed risks trace to,
+      and those tracing to affected requirements
+    - affected risks also include those tracing to affected designs
+    - tests to re-execute are the changed ones and those tracing to affected requirements or risks
+
+    Example: tests to re-execute after changing a design
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `design_specification.md`
+            """
+        # Design specification
+        ## DS-1 - Architecture
+        ### Trace
+        * FS-1
+            """
+        And the following content in `risk_assessment.md`
+            """
+        # Risk assessment
+        ## RISK-1 - Wrong architecture
+        ### Trace
+        * DS-1
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+        ## TEST-2 - Risk tests
+        ### Trace
+        * RISK-1
+        ## TEST-3 - Other tests
+        ### Trace
+        * FS-1
+            """
+        When we check it with `impact DS-1`
+        Then we get an output containing
+            """
+            ## Tests to re-execute
+
+            * TEST-1
+            * TEST-2
+            * TEST-3
+            """
+
+    Example: risks mitigated by a changed design
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `design_specification.md`
+            """
+        # Design specification
+        ## DS-1 - Architecture
+        ### Trace
+        * FS-1
+            """
+        And the following content in `risk_assessment.md`
+            """
+        # Risk assessment
+        ## RISK-1 - Wrong architecture
+        ### Trace
+        * DS-1
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+        ## TEST-2 - Risk tests
+        ### Trace
+        * RISK-1
+            """
+        When we check it with `impact DS-1`
+        Then we get an output containing
+            """
+            ## Affected risks
+
+            * RISK-1
+            """
+
+    Example: tests to re-execute after changing a risk
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `design_specification.md`
+            """
+        # Design specification
+        ## DS-1 - Architecture
+        ### Trace
+        * FS-1
+            """
+        And the following content in `risk_assessment.md`
+            """
+        # Risk assessment
+        ## RISK-1 - Wrong architecture
+        ### Trace
+        * DS-1
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+        ## TEST-2 - Risk tests
+        ### Trace
+        * RISK-1
+        ## TEST-3 - Other tests
+        ### Trace
+        * FS-1
+            """
+        When we check it with `impact RISK-1 --format json`
+        Then we get an output containing
+            """
+            "TEST-2"
+            """
+
+    Example: items changed since a revision
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `design_specification.md`
+            """
+        # Design specification
+        ## DS-1 - Architecture
+        ### Trace
+        * FS-1
+            """
+        And the following content in `risk_assessment.md`
+            """
+        # Risk assessment
+        ## RISK-1 - Wrong architecture
+        ### Trace
+        * DS-1
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+        ## TEST-2 - Risk tests
+        ### Trace
+        * RISK-1
+        ## TEST-3 - Other tests
+        ### Trace
+        * FS-1
+            """
+        And the documentation is committed to git by `Jane Doe`
+        And the following file `risk_assessment.md`
+            """
+            # Risk assessment
+            ## RISK-1 - Wrong architecture
+            The architecture may not fit the requirements.
+            ### Trace
+            * DS-1
+            """
+        When we check it with `impact --since HEAD`
+        Then we get an output containing
+            """
+            Changed items: RISK-1
+            """
+
+    Example: changed item that does not exist
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `design_specification.md`
+            """
+        # Design specification
+        ## DS-1 - Architecture
+        ### Trace
+        * FS-1
+            """
+        And the following content in `risk_assessment.md`
+            """
+        # Risk assessment
+        ## RISK-1 - Wrong architecture
+        ### Trace
+        * DS-1
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+        ## TEST-2 - Risk tests
+        ### Trace
+        * RISK-1
+        ## TEST-3 - Other tests
+        ### Trace
+        * FS-1
+            """
+        When we check it with `impact DS-2`
+        Then we get an error containing
+            """
+            Changed items must exist in the documentation, but DS-2 does not
+            """
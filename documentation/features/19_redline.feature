@@ -0,0 +1,67 @@
+Feature: FS-19 - redline of changes between revisions
+    This solution must render the changes of the documentation between two revisions of its
+    git repository as a redline (`diff <before> <after> --format html`): an HTML document with
+    every added, removed or modified item of every document and feature, and section without
+    identifier of every document, where deleted words are marked as deletions (`<del>`) and
+    inserted words as insertions (`<ins>`).
+
+    Example: modified item in a redline
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        Runs every unit test once.
+        ### Trace
+        * FS-1
+            """
+        And the documentation is committed to git by `Jane Doe`
+        And the following file `verification_plan.md`
+            """
+            # Verification plan
+            ## TEST-1 - Unit tests
+            Runs every unit test twice.
+            ### Trace
+            * FS-1
+            """
+        And the documentation is committed to git by `John Doe`
+        When we check it with `diff HEAD~1 HEAD --format html`
+        Then we get an output containing
+            """
+            Runs every unit test <del>once.</del><ins>twice.</ins>
+            """
+
+    Example: added item in a redline
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the documentation is committed to git by `Jane Doe`
+        And the following file `design_specification.md`
+            """
+            # Design specification
+            ## DS-1 - Architecture
+            """
+        And the documentation is committed to git by `John Doe`
+        When we check it with `diff HEAD~1 HEAD --format html`
+        Then we get an output containing
+            """
+            <h3>DS-1 (added)</h3>
+            <div class="text"><ins>## DS-1 - Architecture</ins></div>
+            """
@@ -0,0 +1,198 @@
+Feature: FS-20 - change control through commit trailers
+    When requested (`--change-control <range>`), this solution must check that every commit
+    in the range (e.g. `origin/main..HEAD`) of the git repository that changes the
+    documentation references a change request via a `Change-Request: CR-<number>` trailer.
+    When approvers are configured in `quality.json`, each of these commits must also be
+    approved by one of them via an `Approved-by:` trailer.
+
+    Example: commit referencing a change request
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the documentation is committed to git by `Jane Doe`
+        And the following file `user_manual.md`
+            """
+            # User manual
+
+            Updated.
+            """
+        And the documentation is committed to git by `Jane Doe` with the message
+            """
+            Update the user manual
+
+            Change-Request: CR-123
+            """
+        When we check it with `--change-control HEAD~1..HEAD`
+        Then we get no error
+
+    Example: commit without a change request
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the documentation is committed to git by `Jane Doe`
+        And the following file `user_manual.md`
+            """
+            # User manual
+
+            Updated.
+            """
+        And the documentation is committed to git by `Jane Doe` with the message
+            """
+            Update the user manual
+            """
+        When we check it with `--change-control HEAD~1..HEAD`
+        Then we get an error containing
+            """
+            must reference a change request
+            """
+
+    Example: change request of an invalid form
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the documentation is committed to git by `Jane Doe`
+        And the following file `user_manual.md`
+            """
+            # User manual
+
+            Updated.
+            """
+        And the documentation is committed to git by `Jane Doe` with the message
+            """
+            Update the user manual
+
+            Change-Request: 123
+            """
+        When we check it with `--change-control HEAD~1..HEAD`
+        Then we get an error containing
+            """
+            Change requests must be of the form CR-<number>, but "123"
+            """
+
+    Example: commit approved by an approver
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `quality.json`
+            """
+            {"approvers": ["john@example.com"]}
+            """
+        And the documentation is committed to git by `Jane Doe`
+        And the following file `user_manual.md`
+            """
+            # User manual
+
+            Updated.
+            """
+        And the documentation is committed to git by `Jane Doe` with the message
+            """
+            Update the user manual
+
+            Change-Request: CR-123
+            Approved-by: John Doe <john@example.com>
+            """
+        When we check it with `--change-control HEAD~1..HEAD`
+        Then we get no error
+
+    Example: commit approved by someone who is not an approver
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `quality.json`
+            """
+            {"approvers": ["john@example.com"]}
+            """
+        And the documentation is committed to git by `Jane Doe`
+        And the following file `user_manual.md`
+            """
+            # User manual
+
+            Updated.
+            """
+        And the documentation is committed to git by `Jane Doe` with the message
+            """
+            Update the user manual
+
+            Change-Request: CR-123
+            Approved-by: Jane Doe <jane@example.com>
+            """
+        When we check it with `--change-control HEAD~1..HEAD`
+        Then we get an error containing
+            """
+            must be approved by an approver in quality.json
+            """
+
+    Example: commit without approval when approvers are configured
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `quality.json`
+            """
+            {"approvers": ["john@example.com"]}
+            """
+        And the documentation is committed to git by `Jane Doe`
+        And the following file `user_manual.md`
+            """
+            # User manual
+
+            Updated.
+            """
+        And the documentation is committed to git by `Jane Doe` with the message
+            """
+            Update the user manual
+
+            Change-Request: CR-123
+            """
+        When we check it with `--change-control HEAD~1..HEAD`
+        Then we get an error containing
+            """
+            must be approved ("Approved-by: <approver>")
+            """
@@ -0,0 +1,198 @@
+Feature: FS-21 - ledger of identifiers
+    When the documentation has a ledger of identifiers (`identifiers.json`), this solution must
+    check that every identifier of the documentation is registered in it with the same title,
+    that identifiers removed from the documentation are marked as removed (tombstones), and
+    that removed identifiers only return when explicitly marked as reinstated, so that an
+    identifier is never reused with a different meaning. This solution must also register new
+    identifiers and mark removed ones in the ledger when requested (`ledger`), which is only
+    possible for the documentation in the file system (not with `--rev`).
+
+    Example: documentation matching its ledger
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `identifiers.json`
+            """
+            {
+                "FS-1": {"title": "something", "status": "active"},
+                "TEST-1": {"title": "Unit tests", "status": "active"},
+                "FS-2": {"title": "something removed", "status": "removed"}
+            }
+            """
+        When we check it
+        Then we get no error
+
+    Example: identifier reused with a different meaning
+        Given the following feature
+            """
+Feature: FS-1 - something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `identifiers.json`
+            """
+            {
+                "FS-1": {"title": "something", "status": "active"},
+                "TEST-1": {"title": "Unit tests", "status": "active"}
+            }
+            """
+        When we check it
+        Then we get an error containing
+            """
+            Identifiers must not be reused with a different meaning, but FS-1 was "something" and is now "something else"
+            """
+
+    Example: removed identifier returning
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `identifiers.json`
+            """
+            {
+                "FS-1": {"title": "something", "status": "removed"},
+                "TEST-1": {"title": "Unit tests", "status": "active"}
+            }
+            """
+        When we check it
+        Then we get an error containing
+            """
+            Removed identifiers can only return when marked as reinstated in identifiers.json, but FS-1 is not
+            """
+
+    Example: removed identifier reinstated
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `identifiers.json`
+            """
+            {
+                "FS-1": {"title": "something", "status": "reinstated"},
+                "TEST-1": {"title": "Unit tests", "status": "active"}
+            }
+            """
+        When we check it
+        Then we get no error
+
+    Example: identifier not registered
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `identifiers.json`
+            """
+            {
+                "FS-1": {"title": "something", "status": "active"}
+            }
+            """
+        When we check it
+        Then we get an error containing
+            """
+            Identifiers must be registered in identifiers.json, but TEST-1 is not
+            """
+
+    Example: removed identifier not marked as removed
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `identifiers.json`
+            """
+            {
+                "FS-1": {"title": "something", "status": "active"},
+                "TEST-1": {"title": "Unit tests", "status": "active"},
+                "DS-1": {"title": "Audit trail", "status": "active"}
+            }
+            """
+        When we check it
+        Then we get an error containing
+            """
+            Identifiers removed from the documentation must be marked as removed in identifiers.json, but DS-1 is not
+            """
+
+    Example: ledger updated
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the following file `identifiers.json`
+            """
+            {
+                "FS-1": {"title": "something", "status": "active"},
+                "DS-1": {"title": "Audit trail", "status": "active"}
+            }
+            """
+        When we check it with `ledger`
+        Then we get an output containing
+            """
+            Removed DS-1
+            Registered TEST-1
+            """
+
+    Example: ledger not updated from a revision
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Unit tests
+        ### Trace
+        * FS-1
+            """
+        And the documentation is committed to git by `Jane Doe <jane@example.com>`
+        When we check it with `--rev HEAD ledger`
+        Then we get an error containing
+            """
+            The ledger must be updated from the documentation in the file system, but it is read at revision HEAD
+            """
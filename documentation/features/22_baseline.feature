@@ -0,0 +1,116 @@
+Feature: FS-22 - release baselines
+    When requested (`baseline create <version>`), this solution must output a baseline of the
+    documentation: the SHA-256 of every document, every feature file and every item, with the
+    version of the specification and of this solution. When requested
+    (`baseline verify <file>`), this solution must check that the documentation matches
+    exactly an approved baseline, reporting every document, feature file and item added,
+    removed or changed since. The baseline must not depend on the order of files in the file
+    system, as the same documentation must give the same baseline on every machine.
+
+    Example: baseline created
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        When we check it with `baseline create 1.0.0`
+        Then we get an output containing
+            """
+            "version": "1.0.0"
+            """
+
+    Example: baseline independent of the order of files
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following file `features/z.feature`
+            """
+Feature: FS-2 - something else
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+* FS-2
+            """
+        When we check it with `baseline create 1.0.0`
+        Then we get an output containing
+            """
+            "items": {
+                "features": {
+                  "FS-1": "
+            """
+
+    Example: documentation matching its baseline
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        When we check it with `baseline create 1.0.0`
+        And we save its output to `baseline.json`
+        And we check it again with `baseline verify baseline.json`
+        Then we get an output containing
+            """
+            The documentation matches baseline 1.0.0
+            """
+
+    Example: documentation changed since its baseline
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following file `baseline.json`
+            """
+            {
+                "version": "1.0.0",
+                "tool_version": "0.1.0",
+                "documents": {
+                    "design_specification.md": "453b85ad0733414b01324dae357a6b25fdca630e0754b6988b5406eb97ac1301",
+                    "risk_assessment.md": "6c8ff4f2b8ef53feca1c1b984ad68f0b24e434b1c7cb1bea99df2084ad1cbdc1",
+                    "verification_plan.md": "0000000000000000000000000000000000000000000000000000000000000000",
+                    "user_manual.md": "7da867a3731257686bb55e0aba2bbbbd6746d8cea71ebc7335779681841fa816",
+                    "operator_manual.md": "572731511cfe9871df8d3504c9b5e6380b5908ccb1666df772f1406a1e649acd",
+                    "retirement_plan.md": "83d12c3634fa9ce33798adf01ef0d715d63abaaece840a25c7ddecb574d977e3"
+                },
+                "features": {
+                    "features/some.feature": "605e854b5407031d6a3d30f3fc867fa01a39a8d1db8bc86ed5c5eae89f666d15"
+                },
+                "items": {
+                    "features": {
+                        "FS-1": "605e854b5407031d6a3d30f3fc867fa01a39a8d1db8bc86ed5c5eae89f666d15"
+                    },
+                    "verification_plan.md": {
+                        "TEST-1": "0000000000000000000000000000000000000000000000000000000000000000",
+                        "TEST-2": "0000000000000000000000000000000000000000000000000000000000000000"
+                    }
+                }
+            }
+            """
+        When we check it with `baseline verify baseline.json`
+        Then we get an error containing
+            """
+            Items of verification_plan.md must match baseline 1.0.0, but TEST-2 was removed
+            """
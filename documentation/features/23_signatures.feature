@@ -0,0 +1,199 @@
+Feature: FS-23 - electronic signatures of baselines
+    When requested (`sign <baseline>`), this solution must sign a baseline with the Ed25519
+    private key of a signer, recording the name of the signer, the meaning of the signature
+    (authored, reviewed or approved) and its time. When requested
+    (`verify-signatures <baseline>`), this solution must check that every signature is valid
+    for the baseline and made with the trusted key of its signer (`public_keys.json`), that
+    the baseline is signed with every required meaning (by default, approved), and that the
+    documentation matches the baseline.
+
+    When requested (`keygen <file>`), this solution must generate an Ed25519 key, writing its
+    private key to a file that only its owner can read, and printing its public key.
+
+    Example: baseline signed by a trusted signer
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following file `key`
+            """
+            9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60
+            """
+        And the following file `public_keys.json`
+            """
+            {"Jane": "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"}
+            """
+        When we check it with `baseline create 1.0.0`
+        And we save its output to `baseline.json`
+        And we check it again with `sign baseline.json --key key --signer Jane --meaning approved`
+        Then we get no error
+        When we check it again with `verify-signatures baseline.json`
+        Then we get an output containing
+            """
+            The documentation matches the baseline, which is signed with every required meaning
+            """
+
+    Example: baseline signed by an untrusted key
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following file `key`
+            """
+            9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60
+            """
+        And the following file `public_keys.json`
+            """
+            {"Jane": "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c"}
+            """
+        When we check it with `baseline create 1.0.0`
+        And we save its output to `baseline.json`
+        And we check it again with `sign baseline.json --key key --signer Jane --meaning approved`
+        Then we get no error
+        When we check it again with `verify-signatures baseline.json`
+        Then we get an error containing
+            """
+            Signatures must be made with a trusted key in public_keys.json, but the signature of Jane (approved) is not
+            """
+
+    Example: baseline missing a required signature
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following file `key`
+            """
+            9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60
+            """
+        And the following file `public_keys.json`
+            """
+            {"Jane": "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"}
+            """
+        And the following file `quality.json`
+            """
+            {"required_signatures": ["reviewed", "approved"]}
+            """
+        When we check it with `baseline create 1.0.0`
+        And we save its output to `baseline.json`
+        And we check it again with `sign baseline.json --key key --signer Jane --meaning approved`
+        Then we get no error
+        When we check it again with `verify-signatures baseline.json`
+        Then we get an error containing
+            """
+            Baselines must be signed as reviewed, but this baseline is not
+            """
+
+    Example: baseline changed after being signed
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following file `baseline.json`
+            """
+            {
+                "version": "1.0.0",
+                "tool_version": "0.1.0",
+                "documents": {},
+                "features": {},
+                "items": {}
+            }
+            """
+        And the following file `key`
+            """
+            9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60
+            """
+        And the following file `public_keys.json`
+            """
+            {"Jane": "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"}
+            """
+        And the following file `baseline.signatures.json`
+            """
+            [
+                {
+                    "signer": "Jane",
+                    "meaning": "approved",
+                    "timestamp": "2026-10-18T23:31:11Z",
+                    "public_key": "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+                    "signature": "201a6702f9627d4754e47ba606acbd5f629a4a42c1ee45a62ca5ade25357dd99d54ec2d2130e65587c0d48b9e644105e95fc68b6540ac17a14e13d687eb83005"
+                }
+            ]
+            """
+        When we check it with `verify-signatures baseline.json`
+        Then we get an error containing
+            """
+            Signatures must be valid for the baseline, but the signature of Jane (approved) is not
+            """
+
+    Example: documentation changed after its baseline was signed
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following file `baseline.json`
+            """
+            {
+                "version": "1.0.0",
+                "tool_version": "0.1.0",
+                "documents": {},
+                "features": {},
+                "items": {}
+            }
+            """
+        And the following file `key`
+            """
+            9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60
+            """
+        And the following file `public_keys.json`
+            """
+            {"Jane": "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"}
+            """
+        When we check it with `sign baseline.json --key key --signer Jane --meaning approved`
+        Then we get no error
+        When we check it again with `verify-signatures baseline.json`
+        Then we get an error containing
+            """
+            Documents must match baseline 1.0.0, but verification_plan.md was added
+            """
+
+    Example: private key only readable by its owner
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        When we check it with `keygen key`
+        Then the file `key` can only be read by its owner
@@ -0,0 +1,193 @@
+Feature: FS-24 - document metadata
+    Each document may start with a YAML front matter, delimited by lines `---`, with its
+    control information: `id`, `version`, `status` (draft, in review, approved or effective),
+    `owner`, `approvers`, `effective_date` and `next_review`. This solution must validate
+    the front matter against this schema and include it in the output. The summary report
+    must show the version, status and effective date of each document with front matter, and
+    a digest of its content as the version of the others.
+
+    Example: document with metadata
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `user_manual.md`
+            """
+---
+id: DOC-7
+version: 1.2.0
+status: approved
+owner: Jane Doe
+approvers:
+  - John Doe
+effective_date: 2024-01-01
+next_review: 2025-01-01
+---
+# User manual
+            """
+        When we check it
+        Then we get an output containing
+            """
+            "owner": "Jane Doe"
+            """
+
+    Example: metadata in the summary report
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `user_manual.md`
+            """
+---
+version: 1.2.0
+status: effective
+effective_date: 2024-01-01
+---
+# User manual
+## USER-1 - Installation
+Install the solution.
+            """
+        When we check it with `summary`
+        Then we get an output containing
+            """
+            | user_manual.md | 1 | 1.2.0 | effective | 2024-01-01 |
+            """
+
+    Example: metadata with an invalid status
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `user_manual.md`
+            """
+---
+status: done
+---
+# User manual
+            """
+        When we check it
+        Then we get an error containing
+            """
+            The front matter of "user_manual.md" must be valid
+            """
+
+    Example: metadata with an unknown field
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `user_manual.md`
+            """
+---
+author: Jane Doe
+---
+# User manual
+            """
+        When we check it
+        Then we get an error containing
+            """
+            The front matter of "user_manual.md" must be valid
+            """
+
+    Example: effective document without an effective date
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `user_manual.md`
+            """
+---
+status: effective
+---
+# User manual
+            """
+        When we check it
+        Then we get an error containing
+            """
+            Effective documents must have an effective_date, but "user_manual.md" does not
+            """
+
+    Example: periodic review before the effective date
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `user_manual.md`
+            """
+---
+effective_date: 2024-01-01
+next_review: 2023-01-01
+---
+# User manual
+            """
+        When we check it
+        Then we get an error containing
+            """
+            The next_review of a document must be after its effective_date
+            """
+
+    Example: front matter without an end
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `user_manual.md`
+            """
+---
+status: draft
+# User manual
+            """
+        When we check it
+        Then we get an error containing
+            """
+            The front matter of "user_manual.md" must end with a line "---", but it does not
+            """
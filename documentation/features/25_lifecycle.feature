@@ -0,0 +1,221 @@
+Feature: FS-25 - lifecycle status gates
+    Documents (via the `status` of their front matter), requirements (via the tags `@draft`
+    or `@in-review` of their feature) and items of documents (via a line `Status: <status>`
+    right under their heading) can be in progress, i.e. draft or in review, and so are the items of documents in progress.
+    Requirements and items in progress are not part of the validated state: they need not be
+    covered, and their trace covers nothing, e.g. a draft test covers neither its requirements
+    nor, in the trace graph, its risks. When requested (`--gate release`, or `--release`), this
+    solution must fail when any document or item is in progress; otherwise, it must only warn
+    about them. The validation summary report must list the documents and items in progress.
+
+    Example: draft requirement not covered by tests
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following file `features/draft.feature`
+            """
+@draft
+Feature: FS-2 - something in progress
+            """
+        When we check it
+        Then we get no error
+
+    Example: requirement covered by a draft test only
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+Status: draft
+### Trace
+* FS-1
+            """
+        When we check it
+        Then we get an error containing
+            """
+            All requirements must be covered by tests, but {"FS-1"} are not
+            """
+
+    Example: risk traced by a draft test only in the trace graph
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+
+## TEST-2 - Tests of the risk
+Status: draft
+### Trace
+* RISK-1
+            """
+        And the following content in `risk_assessment.md`
+            """
+# Risk assessment
+## RISK-1 - Loss of data
+The records are lost.
+            """
+        When we check it with `graph --format dot`
+        Then we get an output containing
+            """
+            "RISK-1" [fillcolor="#fb9a99", color="#e31a1c", penwidth=3];
+            """
+
+    Example: draft requirement at the release gate
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following file `features/draft.feature`
+            """
+@draft
+Feature: FS-2 - something in progress
+            """
+        When we check it with `--gate release`
+        Then we get an error containing
+            """
+            Releases cannot contain documents or items in draft or in review, but FS-2 is draft
+            """
+
+    Example: document in review at the release gate
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `user_manual.md`
+            """
+---
+status: in review
+---
+# User manual
+            """
+        When we check it with `--release`
+        Then we get an error containing
+            """
+            Releases cannot contain documents or items in draft or in review, but "user_manual.md" is in review
+            """
+
+    Example: item in draft at the release gate
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `design_specification.md`
+            """
+# Design specification
+## DS-1 - Audit trail
+Status: draft
+
+Something.
+            """
+        When we check it with `--gate release`
+        Then we get an error containing
+            """
+            Releases cannot contain documents or items in draft or in review, but DS-1 is draft
+            """
+
+    Example: item with an invalid status
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `design_specification.md`
+            """
+# Design specification
+## DS-1 - Audit trail
+Status: done
+            """
+        When we check it
+        Then we get an error containing
+            """
+            Items must have a valid status, but DS-1 does not
+            """
+
+    Example: work in progress in the validation summary report
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following file `features/draft.feature`
+            """
+@draft
+Feature: FS-2 - something in progress
+            """
+        When we check it with `summary`
+        Then we get an output containing
+            """
+            | FS-2 | draft |
+            """
+
+    Example: prose starting with Status is not a status
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `user_manual.md`
+            """
+# User manual
+## USER-1 - Checks
+Run the checks.
+
+Status: the last line shows whether the check passed.
+            """
+        When we check it
+        Then we get no error
@@ -0,0 +1,155 @@
+Feature: FS-26 - periodic review status
+    When requested (`review-status`), this solution must list the documents and requirements
+    overdue or due for their periodic review within a number of days (`--within`, 30 by
+    default), in markdown or JSON. A review is due on the `next_review` of the front
+    matter of a document or, when `review_interval_days` is configured in `quality.json`,
+    that number of days after the last commit modifying the document or requirement.
+    Releases (`--gate release`) must not contain documents or requirements overdue for review.
+
+    Example: document overdue for review
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `user_manual.md`
+            """
+---
+next_review: 2000-01-01
+---
+# User manual
+            """
+        When we check it with `review-status`
+        Then we get an output containing
+            """
+            | user_manual.md | 2000-01-01 | overdue by
+            """
+
+    Example: review status in JSON
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `user_manual.md`
+            """
+---
+next_review: 2000-01-01
+---
+# User manual
+            """
+        When we check it with `review-status --format json`
+        Then we get an output containing
+            """
+            "name": "user_manual.md"
+            """
+
+    Example: document not due for review
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `user_manual.md`
+            """
+---
+next_review: 2999-01-01
+---
+# User manual
+            """
+        When we check it with `review-status`
+        Then we get an output containing
+            """
+            No document or requirement is due for review within 30 days.
+            """
+
+    Example: document due for review within a number of days
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `user_manual.md`
+            """
+---
+next_review: 2999-01-01
+---
+# User manual
+            """
+        When we check it with `review-status --within 1000000`
+        Then we get an output containing
+            """
+            | user_manual.md | 2999-01-01 | due in
+            """
+
+    Example: release with a document overdue for review
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `user_manual.md`
+            """
+---
+next_review: 2000-01-01
+---
+# User manual
+            """
+        When we check it with `--gate release`
+        Then we get an error containing
+            """
+            Releases cannot contain documents or requirements overdue for periodic review, but user_manual.md was due on 2000-01-01
+            """
+
+    Example: review due from the last commit
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following file `quality.json`
+            """
+            {"review_interval_days": 1}
+            """
+        And the documentation is committed to git by `Jane Doe`
+        When we check it with `review-status`
+        Then we get an output containing
+            """
+            | FS-1 |
+            """
@@ -0,0 +1,130 @@
+Feature: FS-27 - revision history
+    Documents may contain a section `## Revision history`, without identifier, with a table
+    of their revisions. When requested (`--revision-history`), this solution must check that
+    the Version and Date columns of this table match the git tags changing the document
+    (the tags where it differs from the previous tag), and must add the revision history of
+    documents without such a section, from git, to the validation summary report.
+
+    Example: revision history matching the git tags
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `user_manual.md`
+            """
+# User manual
+## Revision history
+| Version | Date | Description |
+|---------|------|-------------|
+| v1.0 | 2024-01-01 | First release |
+            """
+        And the documentation is committed to git by `Jane Doe` and tagged `v1.0`
+        When we check it with `--revision-history`
+        Then we get no error
+
+    Example: revision history with a wrong date
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `user_manual.md`
+            """
+# User manual
+## Revision history
+| Version | Date | Description |
+|---------|------|-------------|
+| v1.0 | 2023-12-24 | First release |
+            """
+        And the documentation is committed to git by `Jane Doe` and tagged `v1.0`
+        When we check it with `--revision-history`
+        Then we get an error containing
+            """
+            The revision history of "user_manual.md" must match its git tags, but the date of v1.0 is 2023-12-24 instead of 2024-01-01
+            """
+
+    Example: revision history with an unknown version
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `user_manual.md`
+            """
+# User manual
+## Revision history
+| Version | Date | Description |
+|---------|------|-------------|
+| v1.0 | 2024-01-01 | First release |
+| v2.0 | 2024-01-01 | Second release |
+            """
+        And the documentation is committed to git by `Jane Doe` and tagged `v1.0`
+        When we check it with `--revision-history`
+        Then we get an error containing
+            """
+            The revision history of "user_manual.md" must match its git tags, but v2.0 is not a tag changing it
+            """
+
+    Example: revision history missing a version
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `user_manual.md`
+            """
+# User manual
+## Revision history
+| Version | Date | Description |
+|---------|------|-------------|
+            """
+        And the documentation is committed to git by `Jane Doe` and tagged `v1.0`
+        When we check it with `--revision-history`
+        Then we get an error containing
+            """
+            The revision history of "user_manual.md" must match its git tags, but it does not list v1.0
+            """
+
+    Example: revision history generated into the summary report
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the documentation is committed to git by `Jane Doe` and tagged `v1.0`
+        When we check it with `summary --revision-history`
+        Then we get an output containing
+            """
+            | v1.0 | 2024-01-01 | Documentation |
+            """
@@ -0,0 +1,159 @@
+Feature: FS-28 - sections without identifier
+    Documents may contain sections (`##`) without identifier, e.g. "Purpose", "Scope",
+    "Definitions" or "References", when their heading is allowed for the document in
+    `sections` of the configuration (`quality.json`), e.g. `{"sections": {"user_manual.md": ["Purpose"]}}`.
+    This solution must exclude these sections from the items and trace of the document, and
+    must include them in the JSON output and in the difference and redline between revisions.
+
+    Example: allowed section without identifier
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `user_manual.md`
+            """
+# User manual
+## Purpose
+This manual describes how to use the software.
+## USER-1 - How to use
+            """
+        And the following file `quality.json`
+            """
+            {"sections": {"user_manual.md": ["Purpose"]}}
+            """
+        When we check it
+        Then we get an output containing
+            """
+            "Purpose": "## Purpose\nThis manual describes how to use the software."
+            """
+
+    Example: section without identifier not allowed
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `user_manual.md`
+            """
+# User manual
+## Purpose
+This manual describes how to use the software.
+            """
+        And the following file `quality.json`
+            """
+            {"sections": {"operator_manual.md": ["Purpose"]}}
+            """
+        When we check it
+        Then we get an error containing
+            """
+            "user_manual.md" must contain sections of the form "## ID - title", but "Purpose" is not in this form
+            """
+
+    Example: sections without identifier are not traced
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+## References
+### Trace
+* FS-2
+            """
+        And the following file `quality.json`
+            """
+            {"sections": {"verification_plan.md": ["References"]}}
+            """
+        When we check it
+        Then we get no error
+
+    Example: changed section without identifier
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## Scope
+All tests.
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following file `quality.json`
+            """
+            {"sections": {"verification_plan.md": ["Scope"]}}
+            """
+        And the documentation is committed to git by `Jane Doe`
+        And the following file `verification_plan.md`
+            """
+            # Verification plan
+            ## Scope
+            Unit tests only.
+            ## TEST-1 - Unit tests
+            ### Trace
+            * FS-1
+            """
+        And the documentation is committed to git by `John Doe`
+        When we check it with `diff HEAD~1 HEAD`
+        Then we get an output containing
+            """
+            ## Sections
+
+            ### verification_plan.md
+
+            * Modified: Scope
+            """
+
+    Example: changed section without identifier in a redline
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## Scope
+All tests.
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following file `quality.json`
+            """
+            {"sections": {"verification_plan.md": ["Scope"]}}
+            """
+        And the documentation is committed to git by `Jane Doe`
+        And the following file `verification_plan.md`
+            """
+            # Verification plan
+            ## Scope
+            Unit tests only.
+            ## TEST-1 - Unit tests
+            ### Trace
+            * FS-1
+            """
+        And the documentation is committed to git by `John Doe`
+        When we check it with `diff HEAD~1 HEAD --format html`
+        Then we get an output containing
+            """
+            <h3>Scope (modified)</h3>
+            """
@@ -0,0 +1,109 @@
+Feature: FS-29 - glossary
+    The glossary is optional and is documented in a single markdown file named `glossary.md`
+    starting with `# Glossary`, where each term is either itemized by headings (h2) of the form
+    `## TERM-<id> - <term>`, followed by its definition, or a row of a table with a Term and a
+    Definition column. Terms cannot be traced.
+
+    When there is a glossary, every abbreviation (a word of 2 to 6 upper-case letters, other than
+    the prefix of an identifier) used in the documentation, outside of code, must be defined in
+    it, as a term or as a word of a term, e.g. `GMP (Good manufacturing practice)`. This solution
+    must warn about terms never used in the documentation, and must render the definition of
+    terms as tooltips in the validation summary report in HTML.
+
+    Example: abbreviations defined in the glossary
+        Given the following feature
+            """
+Feature: FS-1 - Export to PDF
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests of the GMP rules
+### Trace
+* FS-1
+            """
+        And the following file `glossary.md`
+            """
+            # Glossary
+
+            | Term | Definition |
+            |------|------------|
+            | PDF | Portable document format |
+
+            ## TERM-1 - GMP (Good manufacturing practice)
+            Rules for the manufacturing of medicinal products.
+            """
+        When we check it
+        Then we get no error
+
+    Example: abbreviation not defined in the glossary
+        Given the following feature
+            """
+Feature: FS-1 - Export to PDF
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests of the API
+### Trace
+* FS-1
+            """
+        And the following file `glossary.md`
+            """
+            # Glossary
+            ## TERM-1 - PDF
+            Portable document format.
+            """
+        When we check it
+        Then we get an error containing
+            """
+            Abbreviations must be defined in the glossary, but API (used in verification_plan.md) is not
+            """
+
+    Example: term never used
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following file `glossary.md`
+            """
+            # Glossary
+            ## TERM-1 - Audit trail
+            Record of the changes of data.
+            """
+        When we check it
+        Then we get a warning containing
+            """
+            "Audit trail" is defined in the glossary, but it is never used
+            """
+
+    Example: glossary tooltips in the summary report
+        Given the following feature
+            """
+Feature: FS-1 - Export to PDF
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following file `glossary.md`
+            """
+            # Glossary
+            ## TERM-1 - PDF
+            Portable document format.
+            """
+        When we check it with `summary --format html`
+        Then we get an output containing
+            """
+            <abbr title="Portable document format.">PDF</abbr>
+            """
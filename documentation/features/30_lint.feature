@@ -0,0 +1,111 @@
+Feature: FS-30 - lint of the wording of requirements
+    When requested (`lint`), this solution must report, in markdown or JSON, the wording of the
+    title, description and steps of requirements that makes them hard to verify, following the
+    rules of the INCOSE guide for writing requirements:
+    - ambiguous terms, e.g. "fast", "user-friendly", "as appropriate" or "etc."
+    - weak modal verbs, e.g. "should" or "may"
+    - passive voice without an actor, e.g. "the record is signed"
+    - compound requirements, joined by "and/or" or, in their title, by "and" or "or"
+
+    The rules, ambiguous terms and weak modal verbs are configured under `lint` of the
+    configuration (`quality.json`). The lint fails when there are findings.
+
+    Example: ambiguous term
+        Given the following feature
+            """
+Feature: FS-1 - Export of the audit trail
+    The export should be fast.
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        When we check it with `lint`
+        Then we get a failure with an output containing
+            """
+            | ambiguous-term | "fast" is ambiguous, use a measurable criterion |
+            """
+
+    Example: weak modal verb
+        Given the following feature
+            """
+Feature: FS-1 - Export of the audit trail
+    The export should be fast.
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        When we check it with `lint`
+        Then we get a failure with an output containing
+            """
+            | weak-modal | "should" is not binding, use "must" |
+            """
+
+    Example: passive voice without an actor
+        Given the following feature
+            """
+Feature: FS-1 - Electronic signatures
+    Scenario: Signing a record
+        When the record is signed
+        Then the record is signed by its author
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        When we check it with `lint --format json`
+        Then we get a failure with an output containing
+            """
+            "rule": "passive-voice"
+            """
+
+    Example: compound requirement
+        Given the following feature
+            """
+Feature: FS-1 - Export and import of records
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        When we check it with `lint`
+        Then we get a failure with an output containing
+            """
+            | compound | "and" joins several requirements, split them |
+            """
+
+    Example: configured rules
+        Given the following feature
+            """
+Feature: FS-1 - Export of the audit trail
+    The export should be fast.
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following file `quality.json`
+            """
+            {"lint": {"rules": ["weak-modal"], "weak_modals": ["could"]}}
+            """
+        When we check it with `lint`
+        Then we get an output containing
+            """
+            There are no findings.
+            """
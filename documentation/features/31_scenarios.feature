@@ -0,0 +1,183 @@
+Feature: FS-31 - structure of scenarios
+    The scenarios of every requirement must
+    - have at least one `Then` step
+    - follow Given, When and Then, without a `Given` after a `When` or `Then`
+    - have unique names within the requirement
+    - when they are scenario outlines, have examples with at least one row, whose columns bind
+      every placeholder of their steps
+
+    Requirements should have at least one scenario or example. This solution must warn about
+    requirements without scenarios and about scenarios of invalid structure, and releases
+    (`--gate release`) cannot contain either.
+
+    Example: scenario without Then
+        Given the following feature
+            """
+Feature: FS-1 - Export of the audit trail
+    Scenario: Export
+        Given an audit trail
+        When we export it
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        When we check it
+        Then we get a warning containing
+            """
+            Scenarios must have at least one Then step, but "Export" of FS-1 does not
+            """
+
+    Example: Given after When
+        Given the following feature
+            """
+Feature: FS-1 - Export of the audit trail
+    Scenario: Export
+        Given an audit trail
+        When we export it
+        Given another audit trail
+        Then we get a file
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        When we check it
+        Then we get a warning containing
+            """
+            Steps of scenarios must follow Given, When and Then, but "Export" of FS-1 has a Given after a When or Then
+            """
+
+    Example: scenarios with the same name
+        Given the following feature
+            """
+Feature: FS-1 - Export of the audit trail
+    Scenario: Export
+        When we export it
+        Then we get a file
+
+    Scenario: Export
+        When we export it twice
+        Then we get two files
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        When we check it
+        Then we get a warning containing
+            """
+            Scenario names must be unique within a requirement, but "Export" of FS-1 is not
+            """
+
+    Example: placeholder not bound by the examples
+        Given the following feature
+            """
+Feature: FS-1 - Export of the audit trail
+    Scenario Outline: Export
+        When we export it as <format>
+        Then we get a <extension> file
+
+        Examples:
+            | format |
+            | PDF    |
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        When we check it
+        Then we get a warning containing
+            """
+            Placeholders of scenario outlines must be bound by their examples, but <extension> of "Export" of FS-1 is not
+            """
+
+    Example: scenario outline without examples
+        Given the following feature
+            """
+Feature: FS-1 - Export of the audit trail
+    Scenario Outline: Export
+        When we export it as <format>
+        Then we get a file
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        When we check it
+        Then we get a warning containing
+            """
+            Scenario outlines must have examples with at least one row, but "Export" of FS-1 does not
+            """
+
+    Example: requirement without scenarios
+        Given the following feature
+            """
+Feature: FS-1 - Export of the audit trail
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        When we check it
+        Then we get a warning containing
+            """
+            FS-1 has no scenario or example
+            """
+
+    Example: release with a requirement without scenarios
+        Given the following feature
+            """
+Feature: FS-1 - Export of the audit trail
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        When we check it with `--gate release`
+        Then we get an error containing
+            """
+            Releases cannot contain requirements without scenarios, but FS-1 has none
+            """
+
+    Example: release with a scenario without Then
+        Given the following feature
+            """
+Feature: FS-1 - Export of the audit trail
+    Scenario: Export
+        Given an audit trail
+        When we export it
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        When we check it with `--gate release`
+        Then we get an error containing
+            """
+            Scenarios must have at least one Then step, but "Export" of FS-1 does not
+            """
@@ -0,0 +1,99 @@
+Feature: FS-32 - placeholders and empty items
+    This solution must report, with their file and line, items of documents whose body outside of code
+    (without their heading and trace) is empty, contains a placeholder or contains fewer
+    words than `min_item_words` of the configuration (`quality.json`, 0 by default).
+    The placeholders are `placeholders` of the configuration, by default `TBD`, `TODO`, `XXX`
+    and `lorem ipsum`, ignoring case. They are reported as warnings, and as errors in releases
+    (`--gate release`).
+
+    Example: empty item
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `risk_assessment.md`
+            """# Risk assessment
+## RISK-1 - example
+            """
+        When we check it
+        Then we get a warning containing
+            """
+            Items must not be empty, but RISK-1 (risk_assessment.md:2) is
+            """
+
+    Example: placeholder in a release
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """# Verification plan
+## TEST-1 - Unit tests
+The unit tests of the software.
+### Trace
+* FS-1
+            """
+        And the following content in `risk_assessment.md`
+            """# Risk assessment
+## RISK-1 - Loss of data
+The mitigation is TBD.
+            """
+        When we check it with `--gate release`
+        Then we get an error containing
+            """
+            Items must not contain placeholders, but RISK-1 (risk_assessment.md:3) contains "TBD"
+            """
+
+    Example: item shorter than the minimum number of words
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """# Verification plan
+## TEST-1 - Unit tests
+Unit tests.
+### Trace
+* FS-1
+            """
+        And the following file `quality.json`
+            """
+            {"min_item_words": 5}
+            """
+        When we check it
+        Then we get a warning containing
+            """
+            Items must contain at least 5 words, but TEST-1 (verification_plan.md:2) contains 2
+            """
+
+    Example: configured placeholders
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """# Verification plan
+## TEST-1 - Unit tests
+To be written.
+### Trace
+* FS-1
+            """
+        And the following file `quality.json`
+            """
+            {"placeholders": ["to be written"]}
+            """
+        When we check it
+        Then we get a warning containing
+            """
+            Items must not contain placeholders, but TEST-1 (verification_plan.md:3) contains "to be written"
+            """
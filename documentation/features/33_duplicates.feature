@@ -0,0 +1,92 @@
+Feature: FS-33 - near-duplicates
+    When requested (`lint --duplicates`), this solution must report, in markdown or JSON, the
+    pairs of requirement descriptions, of scenarios, and of items of the same document whose
+    texts are similar, with an excerpt of both side by side, since duplicates lead to conflicting
+    tests. The similarity is the Jaccard similarity of the sets of 3 consecutive words of the
+    texts, ignoring case, and pairs are reported from `duplicate_threshold` under `lint` of the
+    configuration (`quality.json`, 0.8 by default). The lint fails when there are near-duplicates.
+
+    Example: near-duplicate requirements
+        Given the following feature
+            """
+Feature: FS-1 - Export of the audit trail
+    The solution must export the audit trail of every record as a PDF file.
+    The PDF file must contain the date, the author and the reason of every change of the record.
+            """
+        And the following file `features/other.feature`
+            """
+Feature: FS-2 - Export of the audit trails
+    The solution must export the audit trail of every record as a PDF file.
+    The PDF file must contain the date, the author and the reason of every change of the record.
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+* FS-2
+            """
+        When we check it with `lint --duplicates`
+        Then we get a failure with an output containing
+            """
+            ## FS-2 and FS-1 (86%)
+            """
+
+    Example: near-duplicate items of a document
+        Given the following feature
+            """
+Feature: FS-1 - something
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+            """
+        And the following content in `risk_assessment.md`
+            """# Risk assessment
+## RISK-1 - Loss of records
+The records of the batches are lost when the database of the site fails during a backup.
+The batches cannot be released without their records.
+## RISK-2 - Loss of batch records
+The records of the batches are lost when the database of the site fails during a backup.
+The batches cannot be released without their records.
+            """
+        When we check it with `lint --duplicates --format json`
+        Then we get a failure with an output containing
+            """
+            "second": "RISK-2",
+            """
+
+    Example: no near-duplicates below the threshold
+        Given the following feature
+            """
+Feature: FS-1 - Export of the audit trail
+    The solution must export the audit trail of every record as a PDF file.
+    The PDF file must contain the date, the author and the reason of every change of the record.
+            """
+        And the following file `features/other.feature`
+            """
+Feature: FS-2 - Export of the audit trails
+    The solution must export the audit trail of every record as a PDF file.
+    The PDF file must contain the date, the author and the reason of every change of the record.
+            """
+        And the following content in `verification_plan.md`
+            """
+# Verification plan
+## TEST-1 - Unit tests
+### Trace
+* FS-1
+* FS-2
+            """
+        And the following file `quality.json`
+            """
+            {"lint": {"duplicate_threshold": 0.95}}
+            """
+        When we check it with `lint --duplicates`
+        Then we get an output containing
+            """
+            There are no near-duplicates.
+            """
@@ -0,0 +1,111 @@
+Feature: FS-9 - trace graph
+    This solution must render the trace between requirements, designs, risks,
+    tests and manuals as a graph, in Graphviz's DOT language or as a Mermaid
+    flowchart, so that it can be embedded in markdown documents and wikis.
+
+    Each item is a node coloured by the type of document it belongs to, and each trace
+    is an edge from the item to the item it traces to.
+    Items that can be traced but have no trace (to or from them) are uncovered and
+    must be highlighted.
+
+    The graph may be restricted to the neighbourhood of a single item, i.e. the item and
+    every item tracing, directly or indirectly, to it.
+
+    Example: trace graph in DOT
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Example
+        ### Trace
+        * FS-1
+            """
+        When we check it with `graph --format dot`
+        Then we get an output containing
+            """
+            "TEST-1" -> "FS-1";
+            """
+
+    Example: trace graph in Mermaid
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Example
+        ### Trace
+        * FS-1
+            """
+        When we check it with `graph --format mermaid`
+        Then we get an output containing
+            """
+            TEST_1 --> FS_1
+            """
+
+    Example: uncovered items are highlighted
+        Given the following content in `design_specification.md`
+            """
+        # Design specification
+        ## DS-1 - Architecture
+        Something
+            """
+        When we check it with `graph --format dot`
+        Then we get an output containing
+            """
+            "DS-1" [fillcolor="#b2df8a", color="#e31a1c", penwidth=3];
+            """
+
+    Example: neighbourhood of a requirement
+        Given the following feature
+            """
+Feature: FS-1 - something
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following file `features/other.feature`
+            """
+Feature: FS-2 - something else
+    Scenario: Something
+        When something
+        Then something else
+            """
+        And the following content in `verification_plan.md`
+            """
+        # Verification plan
+        ## TEST-1 - Example
+        ### Trace
+        * FS-1
+        ## TEST-2 - Other example
+        ### Trace
+        * FS-2
+            """
+        When we check it with `graph --format dot --requirement FS-2`
+        Then we get an output not containing
+            """
+            "TEST-1"
+            """
+
+    Example: neighbourhood of a non-existing requirement
+        Given the following content in `design_specification.md`
+            """
+        # Design specification
+        ## DS-1 - Architecture
+        Something
+            """
+        When we check it with `graph --format dot --requirement FS-1`
+        Then we get an error containing
+            """
+            but FS-1 does not exist
+            """
@@ -0,0 +1,372 @@
+use std::path::{Path, PathBuf};
+
+use gherkin::{Scenario, Step, StepType};
+use indexmap::IndexMap;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Serialize;
+
+use super::files;
+use super::specification::{Documents, Error};
+
+/// A step definition declared in source code
+#[derive(Debug, Clone)]
+pub struct StepDefinition {
+    /// the type of steps it matches, or `None` when it matches steps of any type
+    pub ty: Option<StepType>,
+    pub pattern: Regex,
+}
+
+impl StepDefinition {
+    fn matches(&self, ty: StepType, text: &str) -> bool {
+        self.ty.map(|x| x == ty).unwrap_or(true) && self.pattern.is_match(text)
+    }
+}
+
+/// How much of a scenario is automated by step definitions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Automation {
+    /// every step matches a step definition
+    Automated,
+    /// some steps match a step definition
+    #[serde(rename = "partially automated")]
+    PartiallyAutomated,
+    /// no step matches a step definition
+    Manual,
+}
+
+/// The automation of a scenario
+#[derive(Debug, Clone, Serialize)]
+pub struct ScenarioAutomation {
+    pub automation: Automation,
+    /// steps without a matching step definition
+    pub undefined_steps: Vec<String>,
+}
+
+/// Converts a cucumber expression (https://github.com/cucumber/cucumber-expressions) to a regex
+fn cucumber_expression(expression: &str) -> String {
+    let mut result = String::new();
+    let mut chars = expression.chars();
+    let mut word = String::new();
+    // alternatives of the current word, e.g. `cat/dog`
+    let mut alternatives = vec![];
+    let flush = |word: &mut String, alternatives: &mut Vec<String>, result: &mut String| {
+        if alternatives.is_empty() {
+            result.push_str(word);
+        } else {
+            alternatives.push(std::mem::take(word));
+            result.push_str(&format!("(?:{})", alternatives.join("|")));
+            alternatives.clear();
+        }
+        word.clear();
+    };
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(c) = chars.next() {
+                    word.push_str(&regex::escape(&c.to_string()));
+                }
+            }
+            '{' => {
+                let mut name = String::new();
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        break;
+                    }
+                    name.push(c);
+                }
+                word.push_str(match name.as_str() {
+                    "int" => r"-?\d+",
+                    "float" => r"-?\d*\.?\d+",
+                    "word" => r"[^\s]+",
+                    "string" => r#"(?:"[^"]*"|'[^']*')"#,
+                    _ => ".*",
+                });
+            }
+            '(' => {
+                let mut optional = String::new();
+                for c in chars.by_ref() {
+                    if c == ')' {
+                        break;
+                    }
+                    optional.push(c);
+                }
+                word.push_str(&format!("(?:{})?", regex::escape(&optional)));
+            }
+            '/' => alternatives.push(std::mem::take(&mut word)),
+            ' ' => {
+                flush(&mut word, &mut alternatives, &mut result);
+                result.push(' ');
+            }
+            c => word.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    flush(&mut word, &mut alternatives, &mut result);
+    format!("^{result}$")
+}
+
+/// Converts a `parse` format (used by behave, https://github.com/r1chardj0n3s/parse) to a regex
+fn parse_format(format: &str) -> String {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"\{[^}]*\}").unwrap();
+    }
+    let mut result = String::new();
+    let mut last = 0;
+    for field in RE.find_iter(format) {
+        result.push_str(&regex::escape(&format[last..field.start()]));
+        result.push_str(if field.as_str().ends_with(":d}") {
+            r"-?\d+"
+        } else {
+            ".+?"
+        });
+        last = field.end();
+    }
+    result.push_str(&regex::escape(&format[last..]));
+    format!("^{result}$")
+}
+
+fn unescape(literal: &str) -> String {
+    literal.replace("\\\"", "\"").replace("\\\\", "\\")
+}
+
+fn step_type(keyword: &str) -> Option<StepType> {
+    match keyword.to_lowercase().as_str() {
+        "given" => Some(StepType::Given),
+        "when" => Some(StepType::When),
+        "then" => Some(StepType::Then),
+        _ => None,
+    }
+}
+
+/// Returns the step definitions (type, regex) declared in `content`
+fn parse_step_definitions(content: &str) -> Vec<(Option<StepType>, String)> {
+    lazy_static! {
+        // Rust's cucumber, e.g. `#[given(expr = "...")]`, `#[when(regex = r"...")]` or `#[then("...")]`
+        static ref RUST: Regex = Regex::new(
+            r##"#\[\s*(given|when|then)\s*\(\s*(?:(expr|regex)\s*=\s*)?(?:r#"(.*?)"#|r"([^"]*)"|"((?:[^"\\]|\\.)*)")"##
+        )
+        .unwrap();
+        // behave, e.g. `@given("...")` or `@step('...')`
+        static ref BEHAVE: Regex =
+            Regex::new(r#"@(given|when|then|step)\(\s*[ur]?(?:"([^"]*)"|'([^']*)')"#).unwrap();
+        // cucumber-js, e.g. `Given('...')`, `When("...")` or `Then(/^...$/, ...)`
+        static ref JS: Regex = Regex::new(
+            r#"\b(Given|When|Then|defineStep)\(\s*(?:'([^']*)'|"([^"]*)"|`([^`]*)`|/((?:[^/\\\n]|\\.)*)/)"#
+        )
+        .unwrap();
+    }
+
+    let mut definitions = vec![];
+    for captures in RUST.captures_iter(content) {
+        let ty = step_type(&captures[1]);
+        let pattern = captures
+            .get(3)
+            .or_else(|| captures.get(4))
+            .map(|raw| raw.as_str().to_string())
+            .or_else(|| captures.get(5).map(|literal| unescape(literal.as_str())))
+            .unwrap_or_default();
+        let pattern = match captures.get(2).map(|kind| kind.as_str()) {
+            Some("expr") => cucumber_expression(&pattern),
+            Some(_) => pattern,
+            None => format!("^{}$", regex::escape(&pattern)),
+        };
+        definitions.push((ty, pattern));
+    }
+    for captures in BEHAVE.captures_iter(content) {
+        let format = captures.get(2).or_else(|| captures.get(3)).unwrap();
+        definitions.push((step_type(&captures[1]), parse_format(format.as_str())));
+    }
+    for captures in JS.captures_iter(content) {
+        // cucumber-js matches steps regardless of their keyword
+        let pattern = if let Some(regex) = captures.get(5) {
+            regex.as_str().to_string()
+        } else {
+            let expression = captures
+                .get(2)
+                .or_else(|| captures.get(3))
+                .or_else(|| captures.get(4))
+                .unwrap();
+            cucumber_expression(expression.as_str())
+        };
+        definitions.push((None, pattern));
+    }
+    definitions
+}
+
+fn source_files(path: &Path, errors: &mut Vec<String>) -> Vec<PathBuf> {
+    if !path.is_dir() {
+        return vec![path.to_path_buf()];
+    }
+    match files::list_directory(path) {
+        Ok(paths) => paths
+            .into_iter()
+            .flat_map(|path| {
+                if path.is_dir() {
+                    source_files(&path, errors)
+                } else if matches!(
+                    path.extension().and_then(|extension| extension.to_str()),
+                    Some("rs" | "py" | "js" | "ts" | "mjs" | "cjs")
+                ) {
+                    vec![path]
+                } else {
+                    vec![]
+                }
+            })
+            .collect(),
+        Err(error) => {
+            errors.push(error);
+            vec![]
+        }
+    }
+}
+
+/// Returns every step definition declared in the source files in `paths` (files or directories).
+/// Supports Rust's cucumber, behave and cucumber-js.
+pub fn get_step_definitions(paths: &[PathBuf]) -> Result<Vec<StepDefinition>, Error> {
+    let mut errors = vec![];
+    let mut definitions = vec![];
+
+    let sources = paths
+        .iter()
+        .flat_map(|path| source_files(path, &mut errors))
+        .collect::<Vec<_>>();
+    for path in sources {
+        let content = match files::read_file(&path) {
+            Ok(content) => content,
+            Err(error) => {
+                errors.push(error);
+                continue;
+            }
+        };
+        for (ty, pattern) in parse_step_definitions(&content) {
+            match Regex::new(&pattern) {
+                Ok(pattern) => definitions.push(StepDefinition { ty, pattern }),
+                Err(_) => errors.push(format!(
+                    "Step definitions must be valid patterns, but \"{pattern}\" in {} is not",
+                    path.display()
+                )),
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(definitions)
+    } else {
+        Err(Error(errors))
+    }
+}
+
+/// The steps of a scenario, with placeholders of scenario outlines replaced by each row of their examples
+fn expand(scenario: &Scenario) -> Vec<(&Step, Vec<String>)> {
+    let rows = scenario
+        .examples
+        .iter()
+        .filter_map(|examples| examples.table.as_ref())
+        .flat_map(|table| {
+            let header = table.rows.first().cloned().unwrap_or_default();
+            table
+                .rows
+                .iter()
+                .skip(1)
+                .map(move |row| header.iter().cloned().zip(row.iter().cloned()).collect())
+        })
+        .collect::<Vec<Vec<(String, String)>>>();
+
+    scenario
+        .steps
+        .iter()
+        .map(|step| {
+            let texts = if rows.is_empty() {
+                vec![step.value.clone()]
+            } else {
+                rows.iter()
+                    .map(|row| {
+                        row.iter().fold(step.value.clone(), |text, (name, value)| {
+                            text.replace(&format!("<{name}>"), value)
+                        })
+                    })
+                    .collect()
+            };
+            (step, texts)
+        })
+        .collect()
+}
+
+fn scenario_automation(
+    background: &[Step],
+    scenario: &Scenario,
+    definitions: &[StepDefinition],
+) -> ScenarioAutomation {
+    let steps = background
+        .iter()
+        .map(|step| (step, vec![step.value.clone()]))
+        .chain(expand(scenario))
+        .collect::<Vec<_>>();
+
+    let undefined_steps = steps
+        .iter()
+        .filter(|(step, texts)| {
+            !texts.iter().all(|text| {
+                definitions
+                    .iter()
+                    .any(|definition| definition.matches(step.ty, text))
+            })
+        })
+        .map(|(step, _)| format!("{} {}", step.keyword.trim(), step.value))
+        .collect::<Vec<_>>();
+
+    let automation = if undefined_steps.is_empty() {
+        Automation::Automated
+    } else if undefined_steps.len() < steps.len() {
+        Automation::PartiallyAutomated
+    } else {
+        Automation::Manual
+    };
+    ScenarioAutomation {
+        automation,
+        undefined_steps,
+    }
+}
+
+/// Returns the automation of every scenario of every requirement, by requirement and scenario name
+pub fn get_automation(
+    documents: &Documents,
+    definitions: &[StepDefinition],
+) -> IndexMap<String, IndexMap<String, ScenarioAutomation>> {
+    documents
+        .features()
+        .map(|(id, feature)| {
+            let background = feature
+                .background
+                .as_ref()
+                .map(|background| background.steps.clone())
+                .unwrap_or_default();
+
+            let mut scenarios = feature
+                .scenarios
+                .iter()
+                .map(|scenario| {
+                    (
+                        scenario.name.clone(),
+                        scenario_automation(&background, scenario, definitions),
+                    )
+                })
+                .collect::<IndexMap<_, _>>();
+            for rule in &feature.rules {
+                let background = background
+                    .iter()
+                    .chain(rule.background.iter().flat_map(|b| b.steps.iter()))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                scenarios.extend(rule.scenarios.iter().map(|scenario| {
+                    (
+                        scenario.name.clone(),
+                        scenario_automation(&background, scenario, definitions),
+                    )
+                }));
+            }
+            (id.clone(), scenarios)
+        })
+        .collect()
+}
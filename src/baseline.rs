@@ -0,0 +1,166 @@
+use std::path::Path;
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::diff::items;
+use super::files::{self, Source};
+use super::specification::{Documents, Error};
+
+/// SHA-256 digest of `text`, in hexadecimal
+fn sha256(text: &str) -> String {
+    Sha256::digest(text.as_bytes())
+        .iter()
+        .map(|x| format!("{x:02x}"))
+        .collect()
+}
+
+/// Manifest of the content of a release of the documentation, proving that what was approved
+/// is what was released
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Baseline {
+    /// the version of the specification, e.g. `1.2.0`
+    pub version: String,
+    /// the version of this tool that created the baseline
+    pub tool_version: String,
+    /// SHA-256 of every document, by file name
+    pub documents: IndexMap<String, String>,
+    /// SHA-256 of every feature file, by path relative to the documentation
+    pub features: IndexMap<String, String>,
+    /// SHA-256 of every item, by document and identifier
+    pub items: IndexMap<String, IndexMap<String, String>>,
+}
+
+/// Differences between the hashes of `baseline` and `current`, of items of kind `kind`
+fn compare(
+    kind: &str,
+    version: &str,
+    baseline: &IndexMap<String, String>,
+    current: &IndexMap<String, String>,
+) -> Vec<String> {
+    let mut errors = vec![];
+    for (name, hash) in baseline {
+        match current.get(name) {
+            None => errors.push(format!(
+                "{kind} must match baseline {version}, but {name} was removed"
+            )),
+            Some(current) if current != hash => errors.push(format!(
+                "{kind} must match baseline {version}, but {name} changed"
+            )),
+            _ => {}
+        }
+    }
+    for name in current.keys().filter(|name| !baseline.contains_key(*name)) {
+        errors.push(format!(
+            "{kind} must match baseline {version}, but {name} was added"
+        ));
+    }
+    errors
+}
+
+impl Baseline {
+    /// Returns the baseline `version` of the documentation in `project`, read from `source`
+    pub fn try_new(
+        project: &Path,
+        source: &Source,
+        documents: &Documents,
+        version: &str,
+    ) -> Result<Self, Error> {
+        let mut errors = vec![];
+
+        let mut features = IndexMap::new();
+        match source.list_directory(project.join("features")) {
+            Ok(mut paths) => {
+                paths.sort();
+                for path in paths
+                    .into_iter()
+                    .filter(|path| path.extension().unwrap_or_default() == "feature")
+                {
+                    match source.read_file(&path) {
+                        Ok(content) => {
+                            let name =
+                                format!("features/{}", path.file_name().unwrap().to_string_lossy());
+                            features.insert(name, sha256(&content));
+                        }
+                        Err(error) => errors.push(error),
+                    }
+                }
+            }
+            Err(error) => errors.push(error),
+        }
+        if !errors.is_empty() {
+            return Err(Error(errors));
+        }
+
+        let documents_hashes = documents
+            .documents()
+            .into_iter()
+            .filter(|(_, document)| !document.is_empty())
+            .map(|(type_, document)| (type_.file_name().to_string(), sha256(document.text())))
+            .collect();
+        // sorted, as the order of items may depend on the order of files in the file system
+        let mut items = items(documents)
+            .into_iter()
+            .filter(|(_, items)| !items.is_empty())
+            .map(|(name, items)| {
+                let mut hashes = items
+                    .into_iter()
+                    .map(|(id, text)| (id, sha256(&text)))
+                    .collect::<IndexMap<_, _>>();
+                hashes.sort_keys();
+                (name.to_string(), hashes)
+            })
+            .collect::<IndexMap<_, _>>();
+        items.sort_keys();
+
+        Ok(Self {
+            version: version.to_string(),
+            tool_version: env!("CARGO_PKG_VERSION").to_string(),
+            documents: documents_hashes,
+            features,
+            items,
+        })
+    }
+
+    /// Checks that `current` has exactly the content of this baseline
+    pub fn verify(&self, current: &Baseline) -> Vec<String> {
+        let version = &self.version;
+        let mut errors = compare("Documents", version, &self.documents, &current.documents);
+        errors.extend(compare(
+            "Feature files",
+            version,
+            &self.features,
+            &current.features,
+        ));
+
+        let empty = IndexMap::new();
+        let names = self.items.keys().chain(
+            current
+                .items
+                .keys()
+                .filter(|name| !self.items.contains_key(*name)),
+        );
+        for name in names {
+            errors.extend(compare(
+                &format!("Items of {name}"),
+                version,
+                self.items.get(name).unwrap_or(&empty),
+                current.items.get(name).unwrap_or(&empty),
+            ));
+        }
+        errors
+    }
+}
+
+/// Returns the baseline in the file `path`
+pub fn get_baseline(path: &Path) -> Result<Baseline, String> {
+    let content = files::read_file(path)?;
+    serde_json::from_str(&content).map_err(|error| {
+        format!(
+            "{} must be a valid baseline, but it is not: {error}",
+            path.to_string_lossy()
+        )
+    })
+}
@@ -0,0 +1,120 @@
+use std::path::Path;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use super::config::{Config, CONFIG_FILE};
+use super::git::git;
+
+/// A commit changing the documentation, with its change-control trailers
+#[derive(Debug, Clone)]
+struct Commit {
+    id: String,
+    subject: String,
+    change_requests: Vec<String>,
+    approvals: Vec<String>,
+}
+
+impl Commit {
+    fn name(&self) -> String {
+        format!(
+            "{} (\"{}\")",
+            &self.id[..self.id.len().min(7)],
+            self.subject
+        )
+    }
+}
+
+/// Returns the commits in `range` (e.g. `origin/main..HEAD`) changing files in `project`
+fn get_commits(project: &Path, range: &str) -> Result<Vec<Commit>, String> {
+    let format = "--format=%H%x1f%s%x1f\
+        %(trailers:key=Change-Request,valueonly,separator=%x1d)%x1f\
+        %(trailers:key=Approved-by,valueonly,separator=%x1d)%x1e";
+    let output = git(project, &["log", format, range, "--", "."])
+        .map_err(|_| format!("Can't read the commits {range} of the git repository"))?;
+
+    let values = |field: &str| {
+        field
+            .split('\u{1d}')
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .collect::<Vec<_>>()
+    };
+    Ok(output
+        .split('\u{1e}')
+        .filter(|commit| !commit.trim().is_empty())
+        .map(|commit| {
+            let mut fields = commit.trim_start().split('\u{1f}');
+            let mut field = || fields.next().unwrap_or_default();
+            Commit {
+                id: field().to_string(),
+                subject: field().to_string(),
+                change_requests: values(field()),
+                approvals: values(field()),
+            }
+        })
+        .collect())
+}
+
+/// Whether `approval`, of the form `Name <email>`, is by `approver` (a name or an email)
+fn is_approved_by(approval: &str, approver: &str) -> bool {
+    let (name, email) = match approval.split_once('<') {
+        Some((name, email)) => (name.trim(), email.trim_end_matches('>').trim()),
+        None => (approval, ""),
+    };
+    approval == approver || name == approver || email == approver
+}
+
+/// Checks that every commit in `range` (e.g. `origin/main..HEAD`) changing the documentation in
+/// `project` references a change request via a `Change-Request: CR-<number>` trailer and, when
+/// approvers are configured, is approved by one of them via an `Approved-by:` trailer.
+pub fn check_change_control(project: &Path, range: &str, config: &Config) -> Vec<String> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^CR-\d+$").unwrap();
+    }
+
+    let commits = match get_commits(project, range) {
+        Ok(commits) => commits,
+        Err(error) => return vec![error],
+    };
+
+    let mut errors = vec![];
+    for commit in &commits {
+        if commit.change_requests.is_empty() {
+            errors.push(format!(
+                "Commits changing the documentation must reference a change request (\"Change-Request: CR-<number>\"), but {} does not",
+                commit.name()
+            ));
+        }
+        for change_request in &commit.change_requests {
+            if !RE.is_match(change_request) {
+                errors.push(format!(
+                    "Change requests must be of the form CR-<number>, but \"{change_request}\" of {} is not",
+                    commit.name()
+                ));
+            }
+        }
+
+        if config.approvers.is_empty() {
+            continue;
+        }
+        if commit.approvals.is_empty() {
+            errors.push(format!(
+                "Commits changing the documentation must be approved (\"Approved-by: <approver>\"), but {} is not",
+                commit.name()
+            ));
+        } else if !commit.approvals.iter().any(|approval| {
+            config
+                .approvers
+                .iter()
+                .any(|approver| is_approved_by(approval, approver))
+        }) {
+            errors.push(format!(
+                "Commits changing the documentation must be approved by an approver in {CONFIG_FILE}, but {} is approved by {}",
+                commit.name(),
+                commit.approvals.join(", ")
+            ));
+        }
+    }
+    errors
+}
@@ -0,0 +1,64 @@
+use std::path::Path;
+
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+use super::deviations::Severity;
+use super::files::Source;
+use super::lint::LintConfig;
+use super::placeholders::default_placeholders;
+use super::signatures::Meaning;
+
+/// Name of the (optional) configuration file in the documentation's directory
+pub const CONFIG_FILE: &str = "quality.json";
+
+/// Configuration of the checks of the documentation, read from [`CONFIG_FILE`]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// people allowed to approve changes to the documentation, by name or email
+    pub approvers: Vec<String>,
+    /// meanings a baseline must be signed with
+    pub required_signatures: Vec<Meaning>,
+    /// days between periodic reviews of documents and requirements, from their last commit
+    pub review_interval_days: Option<u32>,
+    /// headings of the sections (`##`) without identifier allowed in each document, by file name,
+    /// e.g. `{"user_manual.md": ["Purpose", "Scope"]}`
+    pub sections: IndexMap<String, Vec<String>>,
+    /// rules of the lint of requirements
+    pub lint: LintConfig,
+    /// placeholders that items cannot contain, e.g. `TBD`
+    pub placeholders: Vec<String>,
+    /// minimum number of words of the body of items, without their trace
+    pub min_item_words: usize,
+    /// highest severity of open deviations allowed in a release
+    pub max_open_severity: Severity,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            approvers: vec![],
+            required_signatures: vec![Meaning::Approved],
+            review_interval_days: None,
+            sections: IndexMap::new(),
+            lint: LintConfig::default(),
+            placeholders: default_placeholders(),
+            min_item_words: 0,
+            max_open_severity: Severity::Minor,
+        }
+    }
+}
+
+/// Returns the configuration of the documentation in `project`, or the default configuration
+/// if it has none
+pub fn get_config(project: &Path, source: &Source) -> Result<Config, String> {
+    let path = project.join(CONFIG_FILE);
+    if !source.is_file(&path) {
+        return Ok(Config::default());
+    }
+    let content = source.read_file(path)?;
+    serde_json::from_str(&content).map_err(|error| {
+        format!("{CONFIG_FILE} must be a valid configuration, but it is not: {error}")
+    })
+}
@@ -0,0 +1,202 @@
+use std::str::FromStr;
+
+use indexmap::IndexMap;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::evidence::{Execution, Status};
+use super::markdown::body;
+use super::specification::{Document, DocumentType, Documents};
+
+/// Severity of a deviation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Minor,
+    Major,
+    Critical,
+}
+
+impl FromStr for Severity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "minor" => Ok(Self::Minor),
+            "major" => Ok(Self::Major),
+            "critical" => Ok(Self::Critical),
+            _ => Err(format!(
+                "severity must be one of minor, major or critical, but \"{s}\" is not"
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Minor => write!(f, "minor"),
+            Self::Major => write!(f, "major"),
+            Self::Critical => write!(f, "critical"),
+        }
+    }
+}
+
+/// Resolution status of a deviation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Resolution {
+    /// the deviation is not resolved
+    Open,
+    /// the deviation is not resolved but was accepted, e.g. as a known anomaly of a release
+    Accepted,
+    /// the deviation is resolved
+    Closed,
+}
+
+impl FromStr for Resolution {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "open" => Ok(Self::Open),
+            "accepted" => Ok(Self::Accepted),
+            "closed" => Ok(Self::Closed),
+            _ => Err(format!(
+                "status must be one of open, accepted or closed, but \"{s}\" is not"
+            )),
+        }
+    }
+}
+
+/// A deviation from the expected behavior of the software
+#[derive(Debug, Clone, Serialize)]
+pub struct Deviation {
+    pub severity: Severity,
+    pub status: Resolution,
+}
+
+pub type Deviations = IndexMap<String, Deviation>;
+
+/// Parses the severity (`Severity: <severity>`) and status (`Status: <status>`) of each item of the deviation log
+fn parse(deviations: &Document) -> (Deviations, Vec<String>) {
+    lazy_static! {
+        static ref FIELD: Regex =
+            Regex::new(r"^(?P<key>Severity|Status):\s*(?P<value>.*?)\s*$").unwrap();
+    }
+    let file_name = DocumentType::Deviations.file_name();
+
+    let spans = deviations.spans();
+    let mut fields = IndexMap::<String, (Option<String>, Option<String>)>::new();
+    for (id, item) in deviations.items() {
+        let (severity, status) = fields.entry(id.clone()).or_default();
+        for (_, line) in body(&item, *spans[&id].start()) {
+            if let Some(captures) = FIELD.captures(line) {
+                let value = Some(captures["value"].to_string());
+                match &captures["key"] {
+                    "Severity" => *severity = value,
+                    _ => *status = value,
+                }
+            }
+        }
+    }
+
+    let mut errors = vec![];
+    let deviations = fields
+        .into_iter()
+        .filter_map(|(id, (severity, status))| {
+            let severity = severity
+                .ok_or_else(|| "a line \"Severity: <severity>\"".to_string())
+                .and_then(|x| x.parse::<Severity>());
+            let status = status
+                .ok_or_else(|| "a line \"Status: <status>\"".to_string())
+                .and_then(|x| x.parse::<Resolution>());
+            match (severity, status) {
+                (Ok(severity), Ok(status)) => Some((id, Deviation { severity, status })),
+                (severity, status) => {
+                    for error in [severity.err(), status.err()].into_iter().flatten() {
+                        errors.push(format!(
+                            "Items of \"{file_name}\" must contain {error}, but {id} does not"
+                        ));
+                    }
+                    None
+                }
+            }
+        })
+        .collect();
+    (deviations, errors)
+}
+
+/// Returns the deviations of the documentation
+pub fn get_deviations(documents: &Documents) -> Deviations {
+    parse(documents.document(DocumentType::Deviations)).0
+}
+
+/// Checks that the deviation log is valid against the rest of the documentation
+pub(crate) fn check_deviations(documents: &Documents, deviations: &Document) -> Vec<String> {
+    let (_, mut errors) = parse(deviations);
+
+    let requirements = documents.requirements();
+    let tests = documents.document(DocumentType::Tests).trace();
+    let records = documents.test_records();
+
+    for (deviation, values) in deviations.trace() {
+        for value in values {
+            let is_valid = requirements.contains_key(value)
+                || tests.contains_key(value)
+                || records.contains_key(value);
+            if !is_valid {
+                errors.push(format!("Deviations can only be traced to existing requirements, tests or test records, but {deviation} is traced to something else"));
+            }
+        }
+    }
+
+    for (id, record) in records {
+        let is_traced = deviations
+            .trace()
+            .values()
+            .any(|values| values.contains(id));
+        if !record.passed() && !is_traced {
+            errors.push(format!(
+                "Failed test records must be traced by a deviation, but {id} is not"
+            ));
+        }
+    }
+
+    errors
+}
+
+/// Checks that every failed execution is traced by a deviation, via any of the identifiers it refers to
+pub fn check_failed_executions(documents: &Documents, executions: &[Execution]) -> Vec<String> {
+    let trace = documents.document(DocumentType::Deviations).trace();
+
+    executions
+        .iter()
+        .filter(|execution| execution.status == Status::Failed)
+        .filter(|execution| {
+            !execution
+                .identifiers()
+                .any(|id| trace.values().any(|values| values.contains(id)))
+        })
+        .map(|execution| {
+            format!("Failed executions must be traced by a deviation, but \"{execution}\" is not")
+        })
+        .collect()
+}
+
+/// Checks that no open deviation has a severity above `max_open_severity`
+pub fn check_release(documents: &Documents, max_open_severity: Severity) -> Vec<String> {
+    get_deviations(documents)
+        .into_iter()
+        .filter(|(_, deviation)| {
+            deviation.status == Resolution::Open && deviation.severity > max_open_severity
+        })
+        .map(|(id, deviation)| {
+            format!(
+                "Releases cannot have open deviations with a severity above {max_open_severity}, but {id} is open with severity {}",
+                deviation.severity
+            )
+        })
+        .collect()
+}
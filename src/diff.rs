@@ -0,0 +1,241 @@
+use indexmap::{IndexMap, IndexSet};
+use serde::Serialize;
+
+use super::graph::Graph;
+use super::specification::{DocumentType, Documents};
+
+/// Changes of the items of a document
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DocumentChanges {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub modified: Vec<String>,
+}
+
+impl DocumentChanges {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+/// Change of the tests covering a requirement
+#[derive(Debug, Clone, Serialize)]
+pub struct CoverageChange {
+    pub before: Vec<String>,
+    pub after: Vec<String>,
+}
+
+/// Semantic difference between two versions of the documentation
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Diff {
+    /// changed items, by document
+    pub documents: IndexMap<String, DocumentChanges>,
+    /// changed sections without identifier, by document
+    pub sections: IndexMap<String, DocumentChanges>,
+    /// trace edges (from, to) added
+    pub added_trace: Vec<(String, String)>,
+    /// trace edges (from, to) removed
+    pub removed_trace: Vec<(String, String)>,
+    /// requirements whose covering tests changed
+    pub coverage: IndexMap<String, CoverageChange>,
+}
+
+/// The text of every item of the documentation, by document
+pub(crate) fn items(documents: &Documents) -> IndexMap<&'static str, IndexMap<String, String>> {
+    let mut items = IndexMap::new();
+    items.insert("features", documents.requirements().clone());
+    for (type_, document) in documents.documents() {
+        items.insert(type_.file_name(), document.items());
+    }
+    items.insert(
+        "test_records",
+        documents
+            .test_records()
+            .iter()
+            .map(|(id, record)| (id.clone(), record.text.clone()))
+            .collect(),
+    );
+    items
+}
+
+/// Whether two texts differ other than by formatting, i.e. by whitespace
+pub(crate) fn is_modified(before: &str, after: &str) -> bool {
+    before.split_whitespace().ne(after.split_whitespace())
+}
+
+/// The changes from the `before` to the `after` texts, by identifier
+fn changes(before: &IndexMap<String, String>, after: &IndexMap<String, String>) -> DocumentChanges {
+    DocumentChanges {
+        added: after
+            .keys()
+            .filter(|id| !before.contains_key(*id))
+            .cloned()
+            .collect(),
+        removed: before
+            .keys()
+            .filter(|id| !after.contains_key(*id))
+            .cloned()
+            .collect(),
+        modified: after
+            .iter()
+            .filter(|(id, text)| {
+                before
+                    .get(*id)
+                    .map(|before| is_modified(before, text))
+                    .unwrap_or(false)
+            })
+            .map(|(id, _)| id.clone())
+            .collect(),
+    }
+}
+
+/// The tests covering each requirement, other than tests in progress
+fn coverage(documents: &Documents) -> IndexMap<String, Vec<String>> {
+    let verification_plan = documents.document(DocumentType::Tests);
+    let tests = verification_plan.trace();
+    documents
+        .requirements()
+        .keys()
+        .map(|requirement| {
+            let tests = tests
+                .iter()
+                .filter(|(test, _)| !verification_plan.is_in_progress(test))
+                .filter(|(_, trace)| trace.contains(requirement))
+                .map(|(test, _)| test.clone())
+                .collect();
+            (requirement.clone(), tests)
+        })
+        .collect()
+}
+
+impl Diff {
+    /// Returns the difference from `before` to `after`
+    pub fn new(before: &Documents, after: &Documents) -> Self {
+        let before_items = items(before);
+        let after_items = items(after);
+
+        let documents = after_items
+            .iter()
+            .map(|(name, after_items)| {
+                (name.to_string(), changes(&before_items[name], after_items))
+            })
+            .filter(|(_, changes)| !changes.is_empty())
+            .collect();
+
+        let sections = after
+            .documents()
+            .into_iter()
+            .zip(before.documents())
+            .map(|((type_, after), (_, before))| {
+                (
+                    type_.file_name().to_string(),
+                    changes(before.sections(), after.sections()),
+                )
+            })
+            .filter(|(_, changes)| !changes.is_empty())
+            .collect();
+
+        let before_graph = Graph::new(before);
+        let after_graph = Graph::new(after);
+        let added_trace = after_graph
+            .edges()
+            .difference(before_graph.edges())
+            .cloned()
+            .collect();
+        let removed_trace = before_graph
+            .edges()
+            .difference(after_graph.edges())
+            .cloned()
+            .collect();
+
+        let before_coverage = coverage(before);
+        let after_coverage = coverage(after);
+        let requirements = before_coverage
+            .keys()
+            .chain(after_coverage.keys())
+            .collect::<IndexSet<_>>();
+        let coverage = requirements
+            .into_iter()
+            .filter_map(|requirement| {
+                let before = before_coverage
+                    .get(requirement)
+                    .cloned()
+                    .unwrap_or_default();
+                let after = after_coverage.get(requirement).cloned().unwrap_or_default();
+                (before != after).then(|| (requirement.clone(), CoverageChange { before, after }))
+            })
+            .collect();
+
+        Self {
+            documents,
+            sections,
+            added_trace,
+            removed_trace,
+            coverage,
+        }
+    }
+
+    /// Whether there is no difference
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+            && self.sections.is_empty()
+            && self.added_trace.is_empty()
+            && self.removed_trace.is_empty()
+            && self.coverage.is_empty()
+    }
+
+    /// Renders the difference in markdown, titled with the revisions compared
+    pub fn to_markdown(&self, before: &str, after: &str) -> String {
+        let mut result = format!("# Documentation changes from {before} to {after}\n\n");
+        if self.is_empty() {
+            result.push_str("There are no changes.\n");
+            return result;
+        }
+
+        for (title, documents) in [("Items", &self.documents), ("Sections", &self.sections)] {
+            if documents.is_empty() {
+                continue;
+            }
+            result.push_str(&format!("## {title}\n\n"));
+            for (name, changes) in documents {
+                result.push_str(&format!("### {name}\n\n"));
+                for (label, items) in [
+                    ("Added", &changes.added),
+                    ("Removed", &changes.removed),
+                    ("Modified", &changes.modified),
+                ] {
+                    if !items.is_empty() {
+                        result.push_str(&format!("* {label}: {}\n", items.join(", ")));
+                    }
+                }
+                result.push('\n');
+            }
+        }
+
+        if !self.added_trace.is_empty() || !self.removed_trace.is_empty() {
+            result.push_str("## Trace\n\n");
+            for (from, to) in &self.added_trace {
+                result.push_str(&format!("* Added: {from} → {to}\n"));
+            }
+            for (from, to) in &self.removed_trace {
+                result.push_str(&format!("* Removed: {from} → {to}\n"));
+            }
+            result.push('\n');
+        }
+
+        if !self.coverage.is_empty() {
+            result.push_str("## Coverage\n\n");
+            result.push_str("| Requirement | Before | After |\n");
+            result.push_str("|-------------|--------|-------|\n");
+            for (requirement, change) in &self.coverage {
+                result.push_str(&format!(
+                    "| {requirement} | {} | {} |\n",
+                    change.before.join(", "),
+                    change.after.join(", ")
+                ));
+            }
+            result.push('\n');
+        }
+        result
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::BTreeSet;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Serialize;
+
+use super::markdown::{body, cell, prose};
+use super::specification::{scenarios, Documents};
+
+/// The number of consecutive words of a shingle
+const SHINGLE: usize = 3;
+/// The maximum number of characters of an excerpt
+const EXCERPT: usize = 200;
+
+/// A pair of near-duplicate texts, e.g. two requirements or two scenarios
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Duplicate {
+    /// the requirement, scenario (`FS-1 "name"`) or item of the first text
+    pub first: String,
+    /// the requirement, scenario or item of the second text
+    pub second: String,
+    /// the Jaccard similarity (0 to 1) of the shingles of both texts
+    pub similarity: f64,
+    pub first_excerpt: String,
+    pub second_excerpt: String,
+}
+
+/// A text compared to others
+struct Text {
+    label: String,
+    excerpt: String,
+    /// the shingles of the text: its windows of consecutive lowercase words
+    shingles: BTreeSet<Vec<String>>,
+}
+
+impl Text {
+    fn new(label: String, text: &str) -> Self {
+        lazy_static! {
+            static ref WORD: Regex = Regex::new(r"\w+").unwrap();
+        }
+        let lowercase = text.to_lowercase();
+        let words = WORD
+            .find_iter(&lowercase)
+            .map(|x| x.as_str())
+            .collect::<Vec<_>>();
+        // texts shorter than a shingle are a single shingle of all their words
+        let shingles = words
+            .windows(SHINGLE.min(words.len()).max(1))
+            .map(|shingle| shingle.iter().map(|x| x.to_string()).collect())
+            .collect();
+        Self {
+            label,
+            excerpt: excerpt(text),
+            shingles,
+        }
+    }
+}
+
+/// The first characters of `text` on a single line
+fn excerpt(text: &str) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.chars().count() <= EXCERPT {
+        text
+    } else {
+        format!("{}...", text.chars().take(EXCERPT).collect::<String>())
+    }
+}
+
+/// The Jaccard similarity of two sets
+fn jaccard<T: Ord>(first: &BTreeSet<T>, second: &BTreeSet<T>) -> f64 {
+    let common = first.intersection(second).count();
+    common as f64 / (first.len() + second.len() - common) as f64
+}
+
+/// The pairs of `texts` with a similarity of at least `threshold`
+fn pairs(texts: &[Text], threshold: f64) -> Vec<Duplicate> {
+    let mut duplicates = vec![];
+    for (index, first) in texts.iter().enumerate() {
+        for second in &texts[index + 1..] {
+            let (min, max) = if first.shingles.len() < second.shingles.len() {
+                (first.shingles.len(), second.shingles.len())
+            } else {
+                (second.shingles.len(), first.shingles.len())
+            };
+            // the similarity is at most the ratio of the sizes of both sets
+            if min == 0 || (min as f64) < threshold * max as f64 {
+                continue;
+            }
+            let similarity = jaccard(&first.shingles, &second.shingles);
+            if similarity >= threshold {
+                duplicates.push(Duplicate {
+                    first: first.label.clone(),
+                    second: second.label.clone(),
+                    similarity,
+                    first_excerpt: first.excerpt.clone(),
+                    second_excerpt: second.excerpt.clone(),
+                });
+            }
+        }
+    }
+    duplicates
+}
+
+/// Finds the near-duplicates among the descriptions of the requirements, among their scenarios,
+/// and among the items of each document, most similar first
+pub fn find_duplicates(documents: &Documents, threshold: f64) -> Vec<Duplicate> {
+    let mut descriptions = vec![];
+    let mut scenario_texts = vec![];
+    for (id, feature) in documents.features() {
+        let title = feature
+            .name
+            .split_once(" - ")
+            .map(|(_, title)| title)
+            .unwrap_or_default();
+        let description = feature.description.as_deref().unwrap_or_default();
+        descriptions.push(Text::new(id.clone(), &format!("{title}\n{description}")));
+
+        for scenario in scenarios(&feature) {
+            let steps = scenario
+                .steps
+                .iter()
+                .flat_map(|step| {
+                    std::iter::once(step.value.as_str()).chain(step.docstring.as_deref())
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            scenario_texts.push(Text::new(
+                format!("{id} \"{}\"", scenario.name),
+                &format!("{}\n{steps}", scenario.name),
+            ));
+        }
+    }
+
+    let mut duplicates = pairs(&descriptions, threshold);
+    duplicates.extend(pairs(&scenario_texts, threshold));
+    for (_, document) in documents.documents() {
+        let spans = document.spans();
+        let titles = document.titles();
+        let items = document
+            .items()
+            .into_iter()
+            .map(|(id, item)| {
+                let start = *spans[&id].start();
+                let text = body(&item, start)
+                    .into_iter()
+                    .map(|(_, line)| line)
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let title = titles.get(&id).cloned().unwrap_or_default();
+                let text = prose(&text);
+                Text::new(id, &format!("{title}\n{text}"))
+            })
+            .collect::<Vec<_>>();
+        duplicates.extend(pairs(&items, threshold));
+    }
+    duplicates.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+    duplicates
+}
+
+/// Renders the near-duplicates in markdown, with their excerpts side by side
+pub fn duplicates_to_markdown(duplicates: &[Duplicate]) -> String {
+    let mut result = "# Near-duplicates\n\n".to_string();
+    if duplicates.is_empty() {
+        result.push_str("There are no near-duplicates.\n");
+        return result;
+    }
+    for duplicate in duplicates {
+        result.push_str(&format!(
+            "## {} and {} ({:.0}%)\n\n| {} | {} |\n|---|---|\n| {} | {} |\n\n",
+            duplicate.first,
+            duplicate.second,
+            duplicate.similarity * 100.0,
+            cell(&duplicate.first),
+            cell(&duplicate.second),
+            cell(&duplicate.first_excerpt),
+            cell(&duplicate.second_excerpt),
+        ));
+    }
+    result
+}
@@ -0,0 +1,306 @@
+use std::path::{Path, PathBuf};
+
+use indexmap::{IndexMap, IndexSet};
+use quick_xml::events::{BytesStart, Event};
+use serde::{Deserialize, Serialize};
+
+use super::files;
+use super::specification::{extract_identifier, DocumentType, Documents, Error};
+
+/// Outcome of a single test execution
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Passed,
+    Failed,
+    Skipped,
+}
+
+/// A test execution declared in a results file, e.g. a JUnit `testcase` or a Cucumber scenario
+#[derive(Debug, Clone, Serialize)]
+pub struct Execution {
+    /// the file declaring this execution
+    pub source: String,
+    /// the name of the group of this execution (JUnit `classname`, Cucumber feature name)
+    pub group: String,
+    /// the name of the execution (JUnit `name`, Cucumber scenario name)
+    pub name: String,
+    /// tags of this execution, without `@`
+    pub tags: Vec<String>,
+    pub status: Status,
+}
+
+impl Execution {
+    /// Identifiers this execution refers to, via its group or name of the form `ID - title`, or via its tags
+    pub fn identifiers(&self) -> impl Iterator<Item = &str> {
+        extract_identifier(&self.group)
+            .into_iter()
+            .chain(extract_identifier(&self.name))
+            .chain(self.tags.iter().map(|tag| tag.as_str()))
+    }
+}
+
+impl std::fmt::Display for Execution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} / {}", self.source, self.group, self.name)
+    }
+}
+
+fn attribute(element: &BytesStart, name: &str) -> Result<String, quick_xml::Error> {
+    Ok(element
+        .try_get_attribute(name)?
+        .map(|value| value.unescape_value().map(|value| value.to_string()))
+        .transpose()?
+        .unwrap_or_default())
+}
+
+/// The execution of the JUnit `testcase` `element`, passed until a failure says otherwise
+fn testcase(source: &str, element: &BytesStart) -> Result<Execution, quick_xml::Error> {
+    Ok(Execution {
+        source: source.to_string(),
+        group: attribute(element, "classname")?,
+        name: attribute(element, "name")?,
+        tags: vec![],
+        status: Status::Passed,
+    })
+}
+
+fn read_junit(source: &str, content: &str) -> Result<Vec<Execution>, quick_xml::Error> {
+    let mut reader = quick_xml::Reader::from_str(content);
+    reader.trim_text(true);
+
+    let mut executions = vec![];
+    let mut in_testcase = false;
+    loop {
+        match reader.read_event()? {
+            Event::Start(element) if element.name().as_ref() == b"testcase" => {
+                in_testcase = true;
+                executions.push(testcase(source, &element)?);
+            }
+            // a self-closing testcase has no failure nor property, and no end closing it
+            Event::Empty(element) if element.name().as_ref() == b"testcase" => {
+                executions.push(testcase(source, &element)?);
+            }
+            Event::Start(element) | Event::Empty(element) if in_testcase => {
+                let execution = executions.last_mut().unwrap();
+                match element.name().as_ref() {
+                    b"failure" | b"error" => execution.status = Status::Failed,
+                    b"skipped" if execution.status == Status::Passed => {
+                        execution.status = Status::Skipped
+                    }
+                    b"property" if attribute(&element, "name")? == "tag" => execution.tags.push(
+                        attribute(&element, "value")?
+                            .trim_start_matches('@')
+                            .to_string(),
+                    ),
+                    _ => {}
+                }
+            }
+            Event::End(element) if element.name().as_ref() == b"testcase" => {
+                in_testcase = false;
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(executions)
+}
+
+#[derive(Deserialize)]
+struct CucumberTag {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct CucumberResult {
+    status: String,
+}
+
+#[derive(Deserialize)]
+struct CucumberStep {
+    result: Option<CucumberResult>,
+}
+
+#[derive(Deserialize)]
+struct CucumberElement {
+    #[serde(default)]
+    name: String,
+    #[serde(default, rename = "type")]
+    type_: String,
+    #[serde(default)]
+    tags: Vec<CucumberTag>,
+    #[serde(default)]
+    steps: Vec<CucumberStep>,
+}
+
+#[derive(Deserialize)]
+struct CucumberFeature {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    tags: Vec<CucumberTag>,
+    #[serde(default)]
+    elements: Vec<CucumberElement>,
+}
+
+fn read_cucumber(source: &str, content: &str) -> Result<Vec<Execution>, serde_json::Error> {
+    let features: Vec<CucumberFeature> = serde_json::from_str(content)?;
+
+    Ok(features
+        .into_iter()
+        .flat_map(|feature| {
+            let feature_tags = feature
+                .tags
+                .into_iter()
+                .map(|tag| tag.name.trim_start_matches('@').to_string())
+                .collect::<Vec<_>>();
+            let group = feature.name;
+            feature
+                .elements
+                .into_iter()
+                .filter(|element| element.type_ != "background")
+                .map(move |element| {
+                    let statuses = element
+                        .steps
+                        .iter()
+                        .filter_map(|step| step.result.as_ref())
+                        .map(|result| result.status.as_str())
+                        .collect::<Vec<_>>();
+                    // pending and undefined steps are not executed, like skipped ones
+                    let status = if statuses
+                        .iter()
+                        .any(|s| !["passed", "skipped", "pending", "undefined"].contains(s))
+                    {
+                        Status::Failed
+                    } else if statuses.is_empty() || statuses.iter().any(|s| *s != "passed") {
+                        Status::Skipped
+                    } else {
+                        Status::Passed
+                    };
+                    Execution {
+                        source: source.to_string(),
+                        group: group.clone(),
+                        name: element.name,
+                        tags: feature_tags
+                            .iter()
+                            .cloned()
+                            .chain(
+                                element
+                                    .tags
+                                    .into_iter()
+                                    .map(|tag| tag.name.trim_start_matches('@').to_string()),
+                            )
+                            .collect(),
+                        status,
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect())
+}
+
+fn read_executions(path: &Path, errors: &mut Vec<String>) -> Vec<Execution> {
+    let source = path.display().to_string();
+    let content = match files::read_file(path) {
+        Ok(content) => content,
+        Err(error) => {
+            errors.push(error);
+            return vec![];
+        }
+    };
+
+    let result = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("xml") => read_junit(&source, &content).map_err(|e| e.to_string()),
+        Some("json") => read_cucumber(&source, &content).map_err(|e| e.to_string()),
+        _ => {
+            errors.push(format!(
+                "Evidence must be JUnit XML (.xml) or Cucumber JSON (.json) files, but \"{source}\" is neither"
+            ));
+            return vec![];
+        }
+    };
+    result.unwrap_or_else(|error| {
+        errors.push(format!("\"{source}\" is not a valid results file: {error}"));
+        vec![]
+    })
+}
+
+/// Returns every test execution declared in the results files in `paths`
+pub fn get_executions(paths: &[PathBuf]) -> Result<Vec<Execution>, Error> {
+    let mut errors = vec![];
+
+    let executions = paths
+        .iter()
+        .flat_map(|path| read_executions(path, &mut errors))
+        .collect();
+
+    if errors.is_empty() {
+        Ok(executions)
+    } else {
+        Err(Error(errors))
+    }
+}
+
+/// Verification status of a requirement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VerificationStatus {
+    /// at least one execution passed and none failed
+    Passed,
+    /// at least one execution failed
+    Failed,
+    /// no execution passed or failed
+    Unverified,
+}
+
+/// The executions verifying a requirement
+#[derive(Debug, Clone, Serialize)]
+pub struct Verification {
+    pub status: VerificationStatus,
+    pub executions: Vec<String>,
+}
+
+/// Returns the verification status of every requirement, where an execution verifies a requirement
+/// when it refers to the requirement or to a test traced to the requirement.
+pub fn verify(documents: &Documents, executions: &[Execution]) -> IndexMap<String, Verification> {
+    let tests = documents.document(DocumentType::Tests).trace();
+
+    documents
+        .requirements()
+        .keys()
+        .map(|requirement| {
+            let executions = executions
+                .iter()
+                .filter(|execution| {
+                    execution.identifiers().any(|id| {
+                        id == requirement
+                            || tests
+                                .get(id)
+                                .map(|trace| trace.contains(requirement))
+                                .unwrap_or(false)
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            let statuses = executions
+                .iter()
+                .map(|execution| execution.status)
+                .collect::<IndexSet<_>>();
+            let status = if statuses.contains(&Status::Failed) {
+                VerificationStatus::Failed
+            } else if statuses.contains(&Status::Passed) {
+                VerificationStatus::Passed
+            } else {
+                VerificationStatus::Unverified
+            };
+
+            (
+                requirement.clone(),
+                Verification {
+                    status,
+                    executions: executions.iter().map(|e| e.to_string()).collect(),
+                },
+            )
+        })
+        .collect()
+}
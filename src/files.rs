@@ -4,6 +4,8 @@ use std::{
     path::{Path, PathBuf},
 };
 
+use super::git::git;
+
 pub fn list_directory<P: AsRef<Path>>(path: P) -> Result<Vec<PathBuf>, String> {
     let path1 = path.as_ref().to_str().unwrap().to_string();
 
@@ -24,3 +26,125 @@ pub fn read_file<P: AsRef<Path>>(path: P) -> Result<String, String> {
         .map_err(|_| format!("Can't read {}", path1))?;
     String::from_utf8(chunk).map_err(|_| "File is not valid utf8".to_string())
 }
+
+/// Where the documentation is read from
+#[derive(Debug, Clone, Default)]
+pub enum Source {
+    /// the file system
+    #[default]
+    FileSystem,
+    /// a revision (e.g. a tag or a commit) of the git repository containing the documentation,
+    /// read from the repository's object database without a checkout
+    Git {
+        /// the directory of the documentation in the file system
+        project: PathBuf,
+        /// the directory of the documentation relative to the root of the repository
+        prefix: String,
+        revision: String,
+    },
+}
+
+/// Returns `<revision>:<path>`, the object of `path` (in `project`) in the git repository
+fn object(project: &Path, prefix: &str, revision: &str, path: &Path) -> String {
+    let relative = path.strip_prefix(project).unwrap_or(path);
+    let path = format!("{prefix}{}", relative.to_string_lossy().replace('\\', "/"));
+    format!("{revision}:{}", path.trim_end_matches('/'))
+}
+
+impl Source {
+    /// Returns the source of the documentation in `project` at `revision` of its git repository
+    pub fn git(project: &Path, revision: &str) -> Result<Self, String> {
+        git(
+            project,
+            &["rev-parse", "--verify", &format!("{revision}^{{commit}}")],
+        )
+        .map_err(|_| format!("Can't find revision {revision} in the git repository"))?;
+        let prefix = git(project, &["rev-parse", "--show-prefix"])?
+            .trim()
+            .to_string();
+        Ok(Self::Git {
+            project: project.to_path_buf(),
+            prefix,
+            revision: revision.to_string(),
+        })
+    }
+
+    /// The git revision the documentation is read from, if any
+    pub fn revision(&self) -> Option<&str> {
+        match self {
+            Self::FileSystem => None,
+            Self::Git { revision, .. } => Some(revision),
+        }
+    }
+
+    pub fn list_directory<P: AsRef<Path>>(&self, path: P) -> Result<Vec<PathBuf>, String> {
+        match self {
+            Self::FileSystem => list_directory(path),
+            Self::Git {
+                project,
+                prefix,
+                revision,
+            } => {
+                let path = path.as_ref();
+                let object = object(project, prefix, revision, path);
+                let names = git(project, &["ls-tree", "--full-tree", "--name-only", &object])
+                    .map_err(|_| {
+                        format!("Can't open directory {} at {revision}", path.display())
+                    })?;
+                Ok(names.lines().map(|name| path.join(name)).collect())
+            }
+        }
+    }
+
+    pub fn read_file<P: AsRef<Path>>(&self, path: P) -> Result<String, String> {
+        match self {
+            Self::FileSystem => read_file(path),
+            Self::Git {
+                project,
+                prefix,
+                revision,
+            } => {
+                let path = path.as_ref();
+                let object = object(project, prefix, revision, path);
+                git(project, &["show", &object])
+                    .map_err(|_| format!("Can't open {} at {revision}", path.display()))
+            }
+        }
+    }
+
+    /// Whether `path` is a file
+    pub fn is_file<P: AsRef<Path>>(&self, path: P) -> bool {
+        self.object_type(path.as_ref()) == Some("blob")
+    }
+
+    /// Whether `path` is a directory
+    pub fn is_dir<P: AsRef<Path>>(&self, path: P) -> bool {
+        self.object_type(path.as_ref()) == Some("tree")
+    }
+
+    fn object_type(&self, path: &Path) -> Option<&'static str> {
+        match self {
+            Self::FileSystem => {
+                if path.is_dir() {
+                    Some("tree")
+                } else if path.is_file() {
+                    Some("blob")
+                } else {
+                    None
+                }
+            }
+            Self::Git {
+                project,
+                prefix,
+                revision,
+            } => {
+                let object = object(project, prefix, revision, path);
+                match git(project, &["cat-file", "-t", &object]).ok()?.trim() {
+                    "tree" => Some("tree"),
+                    "blob" => Some("blob"),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
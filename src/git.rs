@@ -0,0 +1,181 @@
+use std::path::Path;
+use std::process::Command;
+
+use chrono::{DateTime, FixedOffset, TimeZone};
+use gherkin::{Feature, GherkinEnv};
+use indexmap::IndexMap;
+use serde::Serialize;
+
+use super::files::Source;
+use super::specification::{extract_identifier, Documents, Error};
+
+/// Runs `git` with `args` in `directory`, returning its standard output
+pub(crate) fn git(directory: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(directory)
+        .output()
+        .map_err(|_| {
+            "git must be installed to read the git repository, but it is not".to_string()
+        })?;
+    if !output.status.success() {
+        return Err(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    String::from_utf8(output.stdout).map_err(|_| "Output of git is not valid utf8".to_string())
+}
+
+/// The last commit modifying an item of the documentation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Attribution {
+    pub author: String,
+    pub email: String,
+    pub date: DateTime<FixedOffset>,
+    pub commit: String,
+}
+
+pub type Attributions = IndexMap<String, Attribution>;
+
+/// Parses a timezone of the form `+0200`
+fn parse_timezone(value: &str) -> Option<FixedOffset> {
+    let sign = if value.starts_with('-') { -1 } else { 1 };
+    let value = value.get(1..5)?;
+    let hours = value[..2].parse::<i32>().ok()?;
+    let minutes = value[2..].parse::<i32>().ok()?;
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Returns the attribution of every line of `file` (relative to `project`) at `revision`
+/// (or the working tree), via `git blame`
+fn blame(project: &Path, revision: Option<&str>, file: &str) -> Result<Vec<Attribution>, String> {
+    let mut args = vec!["blame", "--porcelain"];
+    args.extend(revision);
+    args.extend(["--", file]);
+    let output = git(project, &args)?;
+
+    let mut commits = IndexMap::<String, IndexMap<&str, &str>>::new();
+    let mut lines = vec![];
+    let mut current = None;
+    for line in output.lines() {
+        if line.starts_with('\t') {
+            continue;
+        }
+        let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+        if key.len() == 40 && key.chars().all(|c| c.is_ascii_hexdigit()) {
+            commits.entry(key.to_string()).or_default();
+            lines.push(key.to_string());
+            current = Some(key.to_string());
+        } else if let Some(commit) = current.as_ref().and_then(|x| commits.get_mut(x)) {
+            commit.insert(key, value);
+        }
+    }
+
+    let attributions = commits
+        .iter()
+        .map(|(commit, fields)| {
+            let field = |key: &str| fields.get(key).copied().unwrap_or_default();
+            let date = field("author-time")
+                .parse::<i64>()
+                .ok()
+                .zip(parse_timezone(field("author-tz")))
+                .and_then(|(time, timezone)| timezone.timestamp_opt(time, 0).single())
+                .ok_or_else(|| format!("git blame of {file} has an invalid date for {commit}"))?;
+            let attribution = Attribution {
+                author: field("author").to_string(),
+                email: field("author-mail")
+                    .trim_start_matches('<')
+                    .trim_end_matches('>')
+                    .to_string(),
+                date,
+                commit: commit.clone(),
+            };
+            Ok((commit.as_str(), attribution))
+        })
+        .collect::<Result<IndexMap<_, _>, String>>()?;
+
+    Ok(lines
+        .iter()
+        .map(|commit| attributions[commit.as_str()].clone())
+        .collect())
+}
+
+/// The most recent of `attributions`
+fn last(attributions: &[Attribution]) -> Option<Attribution> {
+    attributions
+        .iter()
+        .max_by_key(|attribution| attribution.date)
+        .cloned()
+}
+
+/// Returns the last commit modifying each item of the documentation, using the git repository
+/// `project` belongs to. Items of documents are attributed by the lines from their heading
+/// until the next item; requirements by their whole feature file.
+pub fn get_attributions(
+    project: &Path,
+    source: &Source,
+    documents: &Documents,
+) -> Result<Attributions, Error> {
+    let mut errors = vec![];
+    let mut attributions = Attributions::new();
+
+    match source.list_directory(project.join("features")) {
+        Ok(paths) => {
+            for path in paths
+                .into_iter()
+                .filter(|path| path.extension().unwrap_or_default() == "feature")
+            {
+                let Ok(content) = source.read_file(&path) else {
+                    continue;
+                };
+                let Ok(feature) = Feature::parse(&content, GherkinEnv::default()) else {
+                    continue;
+                };
+                let Some(id) = extract_identifier(&feature.name) else {
+                    continue;
+                };
+                if !documents.requirements().contains_key(id) {
+                    continue;
+                }
+                let file = format!("features/{}", path.file_name().unwrap().to_string_lossy());
+                match blame(project, source.revision(), &file) {
+                    Ok(lines) => {
+                        if let Some(attribution) = last(&lines) {
+                            attributions.insert(id.to_string(), attribution);
+                        }
+                    }
+                    Err(error) => errors.push(error),
+                }
+            }
+        }
+        Err(error) => errors.push(error),
+    }
+
+    for (type_, document) in documents.documents() {
+        if document.is_empty() {
+            continue;
+        }
+        let lines = match blame(project, source.revision(), type_.file_name()) {
+            Ok(lines) => lines,
+            Err(error) => {
+                errors.push(error);
+                continue;
+            }
+        };
+        for (id, span) in document.spans() {
+            let start = (*span.start()).min(lines.len() + 1) - 1;
+            let end = (*span.end()).min(lines.len());
+            if let Some(attribution) = last(&lines[start..end]) {
+                attributions.insert(id, attribution);
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(attributions)
+    } else {
+        Err(Error(errors))
+    }
+}
@@ -0,0 +1,163 @@
+use indexmap::{IndexMap, IndexSet};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use super::markdown::{find_table, prose};
+use super::specification::{Document, DocumentType, Documents};
+
+/// The definition of each term of the glossary, by term
+pub type Glossary = IndexMap<String, String>;
+
+/// Parses the terms of the glossary: the titles of its items (`## TERM-1 - term`), with their
+/// text as definition, and the rows of a table with a Term and a Definition column
+fn parse_glossary(glossary: &Document) -> Glossary {
+    let mut terms = Glossary::new();
+    let titles = glossary.titles();
+    for (id, text) in glossary.items() {
+        let Some(term) = titles.get(&id) else {
+            continue;
+        };
+        // the text after the heading
+        let definition = text.split_once('\n').map(|(_, x)| x).unwrap_or_default();
+        terms.insert(
+            term.trim().to_string(),
+            prose(definition).trim().to_string(),
+        );
+    }
+
+    if let Some((rows, [term, definition])) = find_table(glossary.body(), ["term", "definition"]) {
+        for row in rows.iter().skip(1) {
+            let cell = |index: usize| row.get(index).map(|x| x.trim()).unwrap_or_default();
+            if !cell(term).is_empty() {
+                terms.insert(cell(term).to_string(), cell(definition).to_string());
+            }
+        }
+    }
+    terms
+}
+
+/// Returns the terms of the glossary of the documentation, if any
+pub fn get_glossary(documents: &Documents) -> Glossary {
+    parse_glossary(documents.document(DocumentType::Glossary))
+}
+
+/// The text of every document (by file name), requirement and test record (by identifier),
+/// outside of code, other than the glossary
+fn texts(documents: &Documents) -> IndexMap<String, String> {
+    let mut texts = IndexMap::new();
+    for (id, content) in documents.requirements() {
+        // Gherkin is not markdown
+        texts.insert(id.clone(), content.clone());
+    }
+    for (type_, document) in documents.documents() {
+        if type_ != DocumentType::Glossary && !document.is_empty() {
+            texts.insert(type_.file_name().to_string(), prose(document.body()));
+        }
+    }
+    for (id, record) in documents.test_records() {
+        texts.insert(id.clone(), prose(&record.text));
+    }
+    texts
+}
+
+/// The abbreviations used in `text`: upper-case words of 2 to 6 letters, other than the prefix of
+/// identifiers (e.g. `FS` of `FS-1`)
+fn abbreviations(text: &str) -> IndexSet<String> {
+    lazy_static! {
+        static ref ABBREVIATION: Regex =
+            Regex::new(r"\b(?P<word>[A-Z]{2,6})\b(?P<id>-\d)?").unwrap();
+    }
+    ABBREVIATION
+        .captures_iter(text)
+        .filter(|captures| captures.name("id").is_none())
+        .map(|captures| captures["word"].to_string())
+        .collect()
+}
+
+/// The tooltip of each term of `glossary` and of the abbreviations in the terms, e.g. `GMP` of
+/// `GMP (Good manufacturing practice)`, by the term or abbreviation
+pub(crate) fn tooltips(glossary: &Glossary) -> IndexMap<String, String> {
+    let mut tooltips = IndexMap::new();
+    for (term, definition) in glossary {
+        for abbreviation in abbreviations(term) {
+            if abbreviation != *term {
+                tooltips.insert(abbreviation, format!("{term}: {definition}"));
+            }
+        }
+    }
+    for (term, definition) in glossary {
+        tooltips.insert(term.clone(), definition.clone());
+    }
+    tooltips
+}
+
+/// The regex matching `term` as a whole word, ignoring case
+fn whole_word(term: &str) -> Regex {
+    Regex::new(&format!(r"(?i)\b{}\b", regex::escape(term))).expect("escaped terms to be valid")
+}
+
+/// Checks that the terms of the glossary are not traced and that every abbreviation used in the
+/// documentation is defined in the glossary, when there is one
+pub(crate) fn check_glossary(documents: &Documents, glossary: &Document) -> Vec<String> {
+    if glossary.is_empty() {
+        return vec![];
+    }
+    let mut errors = vec![];
+    for (term, values) in glossary.trace() {
+        if !values.is_empty() {
+            errors.push(format!(
+                "Glossary cannot be traced, but {term} is traced to something else"
+            ));
+        }
+    }
+
+    let terms = parse_glossary(glossary);
+    // the words of the terms, e.g. `GMP` of `GMP (Good manufacturing practice)`
+    let defined = terms
+        .keys()
+        .flat_map(|term| term.split(|c: char| !c.is_alphanumeric()))
+        .collect::<IndexSet<_>>();
+
+    let mut texts = texts(documents);
+    texts.insert(
+        DocumentType::Glossary.file_name().to_string(),
+        prose(glossary.body()),
+    );
+    let mut undefined = IndexMap::<String, Vec<String>>::new();
+    for (name, text) in &texts {
+        for abbreviation in abbreviations(text) {
+            if !defined.contains(abbreviation.as_str()) {
+                undefined
+                    .entry(abbreviation)
+                    .or_default()
+                    .push(name.clone());
+            }
+        }
+    }
+    for (abbreviation, names) in undefined {
+        errors.push(format!(
+            "Abbreviations must be defined in the glossary, but {abbreviation} (used in {}) is not",
+            names.join(", ")
+        ));
+    }
+    errors
+}
+
+/// Warnings of terms of the glossary never used in the documentation
+pub fn get_glossary_warnings(documents: &Documents) -> Vec<String> {
+    let texts = texts(documents);
+    get_glossary(documents)
+        .keys()
+        .filter(|term| {
+            // a term is also used via its abbreviation, e.g. `GMP (Good manufacturing practice)`
+            let names = std::iter::once(term.to_string())
+                .chain(abbreviations(term))
+                .map(|name| whole_word(&name))
+                .collect::<Vec<_>>();
+            !names
+                .iter()
+                .any(|name| texts.values().any(|text| name.is_match(text)))
+        })
+        .map(|term| format!("\"{term}\" is defined in the glossary, but it is never used"))
+        .collect()
+}
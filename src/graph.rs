@@ -0,0 +1,204 @@
+use indexmap::{IndexMap, IndexSet};
+
+use super::lifecycle::items_in_progress;
+use super::specification::{DocumentType, Documents, Error, SpecificationType};
+
+/// The trace of the documentation as a directed graph, where each item is a node
+/// and each trace is an edge from the item to the item it traces to.
+#[derive(Debug, Clone, Default)]
+pub struct Graph {
+    nodes: IndexMap<String, SpecificationType>,
+    edges: IndexSet<(String, String)>,
+    /// items in progress, which neither cover other items nor need to be covered
+    in_progress: IndexSet<String>,
+}
+
+fn color(type_: SpecificationType) -> &'static str {
+    match type_ {
+        SpecificationType::Requirements => "#a6cee3",
+        SpecificationType::Document(DocumentType::Design) => "#b2df8a",
+        SpecificationType::Document(DocumentType::Risks) => "#fb9a99",
+        SpecificationType::Document(DocumentType::Tests) => "#fdbf6f",
+        SpecificationType::Document(DocumentType::UserManual) => "#cab2d6",
+        SpecificationType::Document(DocumentType::OperatorManual) => "#ffff99",
+        SpecificationType::Document(DocumentType::RetirementPlan) => "#d9d9d9",
+        SpecificationType::Document(DocumentType::Deviations) => "#ff7f00",
+        SpecificationType::Document(DocumentType::Glossary) => "#ffffff",
+    }
+}
+
+fn class(type_: SpecificationType) -> &'static str {
+    match type_ {
+        SpecificationType::Requirements => "requirement",
+        SpecificationType::Document(DocumentType::Design) => "design",
+        SpecificationType::Document(DocumentType::Risks) => "risk",
+        SpecificationType::Document(DocumentType::Tests) => "test",
+        SpecificationType::Document(DocumentType::UserManual) => "user",
+        SpecificationType::Document(DocumentType::OperatorManual) => "operator",
+        SpecificationType::Document(DocumentType::RetirementPlan) => "retirement",
+        SpecificationType::Document(DocumentType::Deviations) => "deviation",
+        SpecificationType::Document(DocumentType::Glossary) => "term",
+    }
+}
+
+const UNCOVERED_COLOR: &str = "#e31a1c";
+
+/// mermaid identifiers can't contain `-`
+fn mermaid_id(id: &str) -> String {
+    id.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+impl Graph {
+    pub fn new(documents: &Documents) -> Self {
+        let mut nodes = IndexMap::new();
+        let mut edges = IndexSet::new();
+
+        for id in documents.requirements().keys() {
+            nodes.insert(id.clone(), SpecificationType::Requirements);
+        }
+        for (type_, document) in documents.documents() {
+            // terms of the glossary are not traced
+            if type_ == DocumentType::Glossary {
+                continue;
+            }
+            for (id, values) in document.trace() {
+                nodes.insert(id.clone(), SpecificationType::Document(type_));
+                for value in values {
+                    edges.insert((id.clone(), value.clone()));
+                }
+            }
+        }
+        Self {
+            nodes,
+            edges,
+            in_progress: items_in_progress(documents),
+        }
+    }
+
+    /// Returns the sub-graph of items that trace, directly or indirectly, to `id`.
+    pub fn neighbourhood(&self, id: &str) -> Result<Self, Error> {
+        if !self.nodes.contains_key(id) {
+            return Err(Error(vec![format!(
+                "The trace graph can only be filtered by existing items, but {id} does not exist"
+            )]));
+        }
+
+        let mut selected = IndexSet::new();
+        selected.insert(id.to_string());
+        let mut pending = vec![id.to_string()];
+        while let Some(current) = pending.pop() {
+            for (from, to) in &self.edges {
+                if *to == current && selected.insert(from.clone()) {
+                    pending.push(from.clone());
+                }
+            }
+        }
+
+        Ok(Self {
+            nodes: self
+                .nodes
+                .iter()
+                .filter(|(id, _)| selected.contains(*id))
+                .map(|(id, type_)| (id.clone(), *type_))
+                .collect(),
+            edges: self
+                .edges
+                .iter()
+                .filter(|(from, to)| selected.contains(from) && selected.contains(to))
+                .cloned()
+                .collect(),
+            in_progress: self.in_progress.clone(),
+        })
+    }
+
+    /// The nodes of this graph, with the type of each item
+    pub fn nodes(&self) -> &IndexMap<String, SpecificationType> {
+        &self.nodes
+    }
+
+    /// The edges of this graph, from the item to the item it traces to
+    pub fn edges(&self) -> &IndexSet<(String, String)> {
+        &self.edges
+    }
+
+    /// Whether an item is uncovered, i.e. it can be traced but nothing traces to it
+    /// and it traces to nothing. Items in progress are never uncovered, and their trace
+    /// covers nothing.
+    pub fn is_uncovered(&self, id: &str) -> bool {
+        let traceable = !matches!(
+            self.nodes.get(id),
+            None | Some(SpecificationType::Document(
+                DocumentType::OperatorManual | DocumentType::RetirementPlan
+            ))
+        );
+        traceable
+            && !self.in_progress.contains(id)
+            && !self
+                .edges
+                .iter()
+                .any(|(from, to)| from == id || (to == id && !self.in_progress.contains(from)))
+    }
+
+    /// Renders the graph in Graphviz's DOT language
+    pub fn to_dot(&self) -> String {
+        let mut result =
+            "digraph trace {\n    rankdir=LR;\n    node [shape=box, style=filled];\n".to_string();
+        for (id, type_) in &self.nodes {
+            let color = color(*type_);
+            if self.is_uncovered(id) {
+                result.push_str(&format!(
+                    "    \"{id}\" [fillcolor=\"{color}\", color=\"{UNCOVERED_COLOR}\", penwidth=3];\n"
+                ));
+            } else {
+                result.push_str(&format!("    \"{id}\" [fillcolor=\"{color}\"];\n"));
+            }
+        }
+        for (from, to) in &self.edges {
+            result.push_str(&format!("    \"{from}\" -> \"{to}\";\n"));
+        }
+        result.push_str("}\n");
+        result
+    }
+
+    /// Renders the graph as a Mermaid flowchart
+    pub fn to_mermaid(&self) -> String {
+        let mut result = "flowchart LR\n".to_string();
+        for (id, type_) in &self.nodes {
+            result.push_str(&format!(
+                "    {}[\"{id}\"]:::{}\n",
+                mermaid_id(id),
+                class(*type_)
+            ));
+        }
+        for (from, to) in &self.edges {
+            result.push_str(&format!(
+                "    {} --> {}\n",
+                mermaid_id(from),
+                mermaid_id(to)
+            ));
+        }
+        let types = self.nodes.values().copied().collect::<IndexSet<_>>();
+        for type_ in types {
+            result.push_str(&format!(
+                "    classDef {} fill:{}\n",
+                class(type_),
+                color(type_)
+            ));
+        }
+        let uncovered = self
+            .nodes
+            .keys()
+            .filter(|id| self.is_uncovered(id))
+            .map(|id| mermaid_id(id))
+            .collect::<Vec<_>>();
+        if !uncovered.is_empty() {
+            result.push_str(&format!(
+                "    classDef uncovered stroke:{UNCOVERED_COLOR},stroke-width:3px\n"
+            ));
+            result.push_str(&format!("    class {} uncovered\n", uncovered.join(",")));
+        }
+        result
+    }
+}
@@ -0,0 +1,147 @@
+use indexmap::IndexSet;
+use serde::Serialize;
+
+use super::diff::Diff;
+use super::graph::Graph;
+use super::specification::{DocumentType, Documents, Error, SpecificationType};
+
+/// Items affected by a change of the documentation, and the tests that must be re-executed
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Impact {
+    pub changed: Vec<String>,
+    pub requirements: Vec<String>,
+    pub risks: Vec<String>,
+    pub designs: Vec<String>,
+    pub tests: Vec<String>,
+}
+
+/// Items of `documents` changed in `diff`: items added or modified, items whose trace changed
+/// and requirements whose coverage changed. Removed items are represented by the items they traced to.
+pub fn changed_items(documents: &Documents, diff: &Diff) -> Vec<String> {
+    let graph = Graph::new(documents);
+    diff.documents
+        .values()
+        .flat_map(|changes| changes.added.iter().chain(changes.modified.iter()))
+        .chain(
+            diff.added_trace
+                .iter()
+                .chain(diff.removed_trace.iter())
+                .flat_map(|(from, to)| [from, to]),
+        )
+        .chain(diff.coverage.keys())
+        .filter(|id| graph.nodes().contains_key(*id))
+        .cloned()
+        .collect::<IndexSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Returns the impact of changing `changed`, where
+/// * affected requirements are the changed ones and those that changed designs or risks trace to,
+///   directly or indirectly
+/// * affected designs and risks are the changed ones, those that changed risks trace to, and those
+///   tracing to affected requirements
+/// * affected risks also include those tracing to affected designs
+/// * tests to re-execute are the changed ones and those tracing to affected requirements or risks
+pub fn impact(documents: &Documents, changed: &[String]) -> Result<Impact, Error> {
+    let graph = Graph::new(documents);
+    let nodes = graph.nodes();
+
+    let errors = changed
+        .iter()
+        .filter(|id| !nodes.contains_key(*id))
+        .map(|id| format!("Changed items must exist in the documentation, but {id} does not"))
+        .collect::<Vec<_>>();
+    if !errors.is_empty() {
+        return Err(Error(errors));
+    }
+
+    let changed = changed.iter().cloned().collect::<IndexSet<_>>();
+
+    let risk = SpecificationType::Document(DocumentType::Risks);
+    let design = SpecificationType::Document(DocumentType::Design);
+    let test = SpecificationType::Document(DocumentType::Tests);
+
+    // changed items and every item changed designs or risks trace to, directly or indirectly
+    let mut affected = changed.clone();
+    let mut pending = changed
+        .iter()
+        .filter(|id| matches!(nodes.get(*id), Some(type_) if *type_ == risk || *type_ == design))
+        .cloned()
+        .collect::<Vec<_>>();
+    while let Some(current) = pending.pop() {
+        for (from, to) in graph.edges() {
+            if *from == current && affected.insert(to.clone()) {
+                pending.push(to.clone());
+            }
+        }
+    }
+
+    let of_type = |items: &IndexSet<String>, type_: SpecificationType| {
+        items
+            .iter()
+            .filter(|id| nodes.get(*id) == Some(&type_))
+            .cloned()
+            .collect::<IndexSet<_>>()
+    };
+    let tracing_to = |targets: &IndexSet<String>, type_: SpecificationType| {
+        graph
+            .edges()
+            .iter()
+            .filter(|(from, to)| targets.contains(to) && nodes.get(from) == Some(&type_))
+            .map(|(from, _)| from.clone())
+            .collect::<IndexSet<_>>()
+    };
+
+    let requirements = of_type(&affected, SpecificationType::Requirements);
+    let mut designs = of_type(&affected, design);
+    designs.extend(tracing_to(&requirements, design));
+    let mut risks = of_type(&affected, risk);
+    risks.extend(tracing_to(&requirements, risk));
+    risks.extend(tracing_to(&designs, risk));
+
+    let targets = requirements.union(&risks).cloned().collect();
+    let mut tests = of_type(&affected, test);
+    tests.extend(tracing_to(&targets, test));
+
+    // report in the order of the documentation
+    let sorted = |items: IndexSet<String>| {
+        nodes
+            .keys()
+            .filter(|id| items.contains(*id))
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+    Ok(Impact {
+        changed: changed.into_iter().collect(),
+        requirements: sorted(requirements),
+        risks: sorted(risks),
+        designs: sorted(designs),
+        tests: sorted(tests),
+    })
+}
+
+impl Impact {
+    /// Renders the impact in markdown
+    pub fn to_markdown(&self) -> String {
+        let mut result = "# Change impact\n\n".to_string();
+        result.push_str(&format!("Changed items: {}\n\n", self.changed.join(", ")));
+        for (title, items) in [
+            ("Affected requirements", &self.requirements),
+            ("Affected risks", &self.risks),
+            ("Affected designs", &self.designs),
+            ("Tests to re-execute", &self.tests),
+        ] {
+            result.push_str(&format!("## {title}\n\n"));
+            if items.is_empty() {
+                result.push_str("None.\n\n");
+            } else {
+                for item in items {
+                    result.push_str(&format!("* {item}\n"));
+                }
+                result.push('\n');
+            }
+        }
+        result
+    }
+}
@@ -0,0 +1,148 @@
+use std::path::Path;
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use super::files::Source;
+use super::specification::{Documents, Error};
+
+/// Name of the (optional) ledger of every identifier ever used, in the documentation's directory
+pub const LEDGER_FILE: &str = "identifiers.json";
+
+/// Status of an identifier in the ledger
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IdentifierStatus {
+    Active,
+    /// the item was removed from the documentation; its identifier is a tombstone
+    Removed,
+    /// the item was removed and later explicitly brought back, with the same meaning
+    Reinstated,
+}
+
+/// An identifier registered in the ledger
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LedgerEntry {
+    pub title: String,
+    pub status: IdentifierStatus,
+}
+
+/// Every identifier ever used in the documentation, with its title
+pub type Ledger = IndexMap<String, LedgerEntry>;
+
+/// The title of every item of the documentation, e.g. `something` of `Feature: FS-1 - something`
+fn titles(documents: &Documents) -> IndexMap<String, String> {
+    let mut titles = documents
+        .features()
+        .map(|(id, feature)| {
+            let title = feature.name.split_once(" - ").map(|x| x.1).unwrap_or("");
+            (id.clone(), title.to_string())
+        })
+        .collect::<IndexMap<_, _>>();
+    for (_, document) in documents.documents() {
+        titles.extend(document.titles());
+    }
+    titles
+}
+
+/// Whether two titles are the same other than by whitespace
+fn same_title(a: &str, b: &str) -> bool {
+    a.split_whitespace().eq(b.split_whitespace())
+}
+
+/// Returns the ledger of the documentation in `project`, if it has one
+pub fn get_ledger(project: &Path, source: &Source) -> Result<Option<Ledger>, String> {
+    let path = project.join(LEDGER_FILE);
+    if !source.is_file(&path) {
+        return Ok(None);
+    }
+    let content = source.read_file(path)?;
+    serde_json::from_str(&content).map(Some).map_err(|error| {
+        format!("{LEDGER_FILE} must be a valid ledger of identifiers, but it is not: {error}")
+    })
+}
+
+/// Errors of identifiers reused with a different meaning (title), or removed and returning
+/// without being marked as reinstated
+fn check_reuse(titles: &IndexMap<String, String>, ledger: &Ledger) -> Vec<String> {
+    let mut errors = vec![];
+    for (id, title) in titles {
+        let Some(entry) = ledger.get(id) else {
+            continue;
+        };
+        if entry.status == IdentifierStatus::Removed {
+            errors.push(format!(
+                "Removed identifiers can only return when marked as reinstated in {LEDGER_FILE}, but {id} is not"
+            ));
+        } else if !same_title(&entry.title, title) {
+            errors.push(format!(
+                "Identifiers must not be reused with a different meaning, but {id} was \"{}\" and is now \"{title}\"",
+                entry.title
+            ));
+        }
+    }
+    errors
+}
+
+/// Checks the documentation against its ledger: every identifier must be registered, keep its
+/// title, and removed identifiers must be marked as removed and only return when reinstated.
+pub fn check_ledger(documents: &Documents, ledger: &Ledger) -> Vec<String> {
+    let titles = titles(documents);
+
+    let mut errors = titles
+        .keys()
+        .filter(|id| !ledger.contains_key(*id))
+        .map(|id| format!("Identifiers must be registered in {LEDGER_FILE}, but {id} is not"))
+        .collect::<Vec<_>>();
+    errors.extend(check_reuse(&titles, ledger));
+    errors.extend(
+        ledger
+            .iter()
+            .filter(|(id, entry)| {
+                entry.status != IdentifierStatus::Removed && !titles.contains_key(*id)
+            })
+            .map(|(id, _)| {
+                format!(
+                    "Identifiers removed from the documentation must be marked as removed in {LEDGER_FILE}, but {id} is not"
+                )
+            }),
+    );
+    errors
+}
+
+/// Returns `ledger` updated with the documentation, with new identifiers registered and removed
+/// ones marked as removed, and the list of changes made. Identifiers reused with a different
+/// meaning or returning without being reinstated are errors, as they must be resolved by hand.
+pub fn update_ledger(
+    documents: &Documents,
+    mut ledger: Ledger,
+) -> Result<(Ledger, Vec<String>), Error> {
+    let titles = titles(documents);
+
+    let errors = check_reuse(&titles, &ledger);
+    if !errors.is_empty() {
+        return Err(Error(errors));
+    }
+
+    let mut changes = vec![];
+    for (id, entry) in ledger.iter_mut() {
+        if entry.status != IdentifierStatus::Removed && !titles.contains_key(id) {
+            entry.status = IdentifierStatus::Removed;
+            changes.push(format!("Removed {id}"));
+        }
+    }
+    for (id, title) in titles {
+        if !ledger.contains_key(&id) {
+            changes.push(format!("Registered {id}"));
+            ledger.insert(
+                id,
+                LedgerEntry {
+                    title,
+                    status: IdentifierStatus::Active,
+                },
+            );
+        }
+    }
+    Ok((ledger, changes))
+}
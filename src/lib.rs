@@ -1,24 +1,81 @@
 use std::path::PathBuf;
 
+mod automation;
+mod baseline;
+mod change_control;
+mod config;
+mod deviations;
+mod diff;
+mod duplicates;
+mod evidence;
 mod files;
+mod git;
+mod glossary;
+mod graph;
+mod impact;
+mod ledger;
+mod lifecycle;
+mod lint;
+mod markdown;
+mod metadata;
+mod placeholders;
+mod records;
+mod redline;
+mod review;
+mod revisions;
+mod scenarios;
+mod signatures;
 mod specification;
+mod summary;
+pub use automation::*;
+pub use baseline::*;
+pub use change_control::*;
+pub use config::*;
+pub use deviations::*;
+pub use diff::*;
+pub use duplicates::*;
+pub use evidence::*;
+pub use files::Source;
+pub use git::*;
+pub use glossary::*;
+pub use graph::*;
+pub use impact::*;
+pub use ledger::*;
+pub use lifecycle::*;
+pub use lint::*;
+pub use metadata::*;
+pub use placeholders::*;
+pub use records::*;
+pub use redline::*;
+pub use review::*;
+pub use revisions::*;
+pub use scenarios::*;
+pub use signatures::*;
 pub use specification::*;
+pub use summary::*;
 
 fn get_document(
     project: PathBuf,
     type_: DocumentType,
+    source: &Source,
+    config: &Config,
     errors: &mut Vec<String>,
 ) -> Option<Document> {
     let path = project.join(type_.file_name());
 
-    let content = match files::read_file(path) {
+    let content = match source.read_file(path) {
         Ok(content) => content,
         Err(error) => {
             errors.push(error);
             return None;
         }
     };
-    match Document::try_new(content, type_) {
+    let sections = config
+        .sections
+        .get(type_.file_name())
+        .map(|x| x.as_slice())
+        .unwrap_or_default();
+    match Document::try_new(content, type_, sections) {
         Ok(document) => Some(document),
         Err(Error(new_errors)) => {
             errors.extend(new_errors);
@@ -27,18 +84,86 @@ fn get_document(
     }
 }
 
+/// Returns the document of type `type_` if it exists, e.g. for optional documents
+fn get_optional_document(
+    project: PathBuf,
+    type_: DocumentType,
+    source: &Source,
+    config: &Config,
+    errors: &mut Vec<String>,
+) -> Option<Document> {
+    if source.is_file(project.join(type_.file_name())) {
+        get_document(project, type_, source, config, errors)
+    } else {
+        Some(Document::default())
+    }
+}
+
 /// Returns the set of all documents defining the software's specification,
 /// as specified in
-pub fn get_documents(project: PathBuf) -> Result<Documents, Error> {
+///
+/// The documentation is read from `source`, e.g. the file system or a revision of its git repository,
+/// with its configuration `config`.
+pub fn get_documents(
+    project: PathBuf,
+    source: &Source,
+    config: &Config,
+) -> Result<Documents, Error> {
     let mut errors = vec![];
 
-    let requirements = get_specification(project.clone(), &mut errors);
-    let design = get_document(project.clone(), DocumentType::Design, &mut errors);
-    let risk_assessment = get_document(project.clone(), DocumentType::Risks, &mut errors);
-    let verification_plan = get_document(project.clone(), DocumentType::Tests, &mut errors);
-    let user_manual = get_document(project.clone(), DocumentType::UserManual, &mut errors);
-    let operator_manual = get_document(project.clone(), DocumentType::OperatorManual, &mut errors);
-    let retirement_plan = get_document(project, DocumentType::RetirementPlan, &mut errors);
+    let requirements = get_specification(project.clone(), source, &mut errors);
+    let design = get_document(
+        project.clone(),
+        DocumentType::Design,
+        source,
+        config,
+        &mut errors,
+    );
+    let risk_assessment = get_document(
+        project.clone(),
+        DocumentType::Risks,
+        source,
+        config,
+        &mut errors,
+    );
+    let verification_plan = get_document(
+        project.clone(),
+        DocumentType::Tests,
+        source,
+        config,
+        &mut errors,
+    );
+    let user_manual = get_document(
+        project.clone(),
+        DocumentType::UserManual,
+        source,
+        config,
+        &mut errors,
+    );
+    let operator_manual = get_document(
+        project.clone(),
+        DocumentType::OperatorManual,
+        source,
+        config,
+        &mut errors,
+    );
+    let retirement_plan = get_document(
+        project.clone(),
+        DocumentType::RetirementPlan,
+        source,
+        config,
+        &mut errors,
+    );
+    let test_records = get_test_records(&project, source, &mut errors);
+    let deviations = get_optional_document(
+        project.clone(),
+        DocumentType::Deviations,
+        source,
+        config,
+        &mut errors,
+    );
+    let glossary =
+        get_optional_document(project, DocumentType::Glossary, source, config, &mut errors);
 
     if errors.is_empty() {
         Documents::try_new(
@@ -50,6 +175,9 @@ pub fn get_documents(project: PathBuf) -> Result<Documents, Error> {
             operator_manual.unwrap(),
             retirement_plan.unwrap(),
         )
+        .and_then(|documents| documents.with_test_records(test_records))
+        .and_then(|documents| documents.with_deviations(deviations.unwrap()))
+        .and_then(|documents| documents.with_glossary(glossary.unwrap()))
     } else {
         Err(Error(errors))
     }
@@ -0,0 +1,154 @@
+use gherkin::{Feature, GherkinEnv};
+use indexmap::{IndexMap, IndexSet};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use super::metadata::DocumentStatus;
+use super::specification::{DocumentType, Documents};
+
+/// A document or item in progress
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InProgress {
+    Document(DocumentType),
+    /// a requirement or item, by identifier
+    Item(String),
+}
+
+impl InProgress {
+    /// The file name of the document, or the identifier of the item
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Document(type_) => type_.file_name(),
+            Self::Item(id) => id,
+        }
+    }
+}
+
+impl std::fmt::Display for InProgress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Document(type_) => write!(f, "\"{}\"", type_.file_name()),
+            Self::Item(id) => write!(f, "{id}"),
+        }
+    }
+}
+
+/// The status of a requirement, from the tags `@draft` or `@in-review` of its feature
+pub(crate) fn requirement_status(feature: &Feature) -> Option<DocumentStatus> {
+    feature
+        .tags
+        .iter()
+        .find_map(|tag| match tag.trim_start_matches('@') {
+            "draft" => Some(DocumentStatus::Draft),
+            "in-review" => Some(DocumentStatus::InReview),
+            _ => None,
+        })
+}
+
+/// Whether the requirement with Gherkin `content` is in progress, i.e. draft or in review
+pub(crate) fn is_requirement_in_progress(content: &str) -> bool {
+    Feature::parse(content, GherkinEnv::default())
+        .ok()
+        .and_then(|feature| requirement_status(&feature))
+        .map(|status| status.is_in_progress())
+        .unwrap_or(false)
+}
+
+/// The value of the line `Status: <value>` right under the heading of the markdown item `text`,
+/// if any, so that prose starting with `Status:` further down is not taken for a status
+fn status_line(text: &str) -> Option<String> {
+    lazy_static! {
+        static ref STATUS: Regex = Regex::new(r"^Status:\s*(?P<value>.*?)\s*$").unwrap();
+    }
+    let line = text.lines().skip(1).find(|line| !line.trim().is_empty())?;
+    STATUS
+        .captures(line.trim())
+        .map(|captures| captures["value"].to_string())
+}
+
+/// Parses the status (`Status: <status>` under its heading) of each item of a document from its markdown `items`
+pub(crate) fn parse_statuses(
+    items: &IndexMap<String, String>,
+) -> Result<IndexMap<String, DocumentStatus>, Vec<String>> {
+    let mut errors = vec![];
+    let statuses = items
+        .iter()
+        .filter_map(
+            |(id, text)| match status_line(text)?.parse::<DocumentStatus>() {
+                Ok(status) => Some((id.clone(), status)),
+                Err(error) => {
+                    errors.push(format!(
+                        "Items must have a valid status, but {id} does not: {error}"
+                    ));
+                    None
+                }
+            },
+        )
+        .collect();
+    if errors.is_empty() {
+        Ok(statuses)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Documents and items still in progress, i.e. draft or in review
+pub fn get_in_progress(documents: &Documents) -> IndexMap<InProgress, DocumentStatus> {
+    let mut in_progress = IndexMap::new();
+    for (type_, document) in documents.documents() {
+        if let Some(status) = document.metadata().and_then(|metadata| metadata.status) {
+            in_progress.insert(InProgress::Document(type_), status);
+        }
+    }
+    for (id, feature) in documents.features() {
+        if let Some(status) = requirement_status(&feature) {
+            in_progress.insert(InProgress::Item(id.clone()), status);
+        }
+    }
+    for (_, document) in documents.documents() {
+        for (id, status) in document.statuses() {
+            in_progress.insert(InProgress::Item(id.clone()), *status);
+        }
+    }
+    in_progress.retain(|_, status| status.is_in_progress());
+    in_progress
+}
+
+/// The identifiers of the requirements and items in progress, themselves or because their
+/// document is, which neither cover other items nor need to be covered
+pub(crate) fn items_in_progress(documents: &Documents) -> IndexSet<String> {
+    let mut in_progress = documents
+        .requirements()
+        .iter()
+        .filter(|(_, content)| is_requirement_in_progress(content))
+        .map(|(id, _)| id.clone())
+        .collect::<IndexSet<_>>();
+    for (_, document) in documents.documents() {
+        in_progress.extend(
+            document
+                .trace()
+                .keys()
+                .filter(|id| document.is_in_progress(id))
+                .cloned(),
+        );
+    }
+    in_progress
+}
+
+/// Checks that no document or item is still in progress, as required for a release
+pub fn check_release_status(documents: &Documents) -> Vec<String> {
+    get_in_progress(documents)
+        .into_iter()
+        .map(|(name, status)| {
+            format!("Releases cannot contain documents or items in draft or in review, but {name} is {status}")
+        })
+        .collect()
+}
+
+/// Warnings of documents or items still in progress, which are not part of the validated state
+pub fn get_status_warnings(documents: &Documents) -> Vec<String> {
+    get_in_progress(documents)
+        .into_iter()
+        .map(|(name, status)| format!("{name} is {status} and is not part of the validated state"))
+        .collect()
+}
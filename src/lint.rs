@@ -0,0 +1,286 @@
+use gherkin::{Feature, Step};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::markdown::cell;
+use super::specification::{scenarios, Documents};
+
+/// A rule of the lint of the wording of requirements
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LintRule {
+    /// terms open to interpretation, e.g. "fast" or "as appropriate"
+    AmbiguousTerm,
+    /// modal verbs that do not make a requirement binding, e.g. "should" or "may"
+    WeakModal,
+    /// passive voice without an actor, e.g. "the record is signed"
+    PassiveVoice,
+    /// several requirements in one, e.g. joined by "and/or"
+    Compound,
+}
+
+impl LintRule {
+    const ALL: [LintRule; 4] = [
+        Self::AmbiguousTerm,
+        Self::WeakModal,
+        Self::PassiveVoice,
+        Self::Compound,
+    ];
+}
+
+impl std::fmt::Display for LintRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AmbiguousTerm => write!(f, "ambiguous-term"),
+            Self::WeakModal => write!(f, "weak-modal"),
+            Self::PassiveVoice => write!(f, "passive-voice"),
+            Self::Compound => write!(f, "compound"),
+        }
+    }
+}
+
+/// Configuration of the lint of requirements, under `lint` of the configuration file
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LintConfig {
+    /// the rules to check
+    pub rules: Vec<LintRule>,
+    /// the terms reported by [`LintRule::AmbiguousTerm`]
+    pub ambiguous_terms: Vec<String>,
+    /// the verbs reported by [`LintRule::WeakModal`]
+    pub weak_modals: Vec<String>,
+    /// the similarity (0 to 1) from which texts are reported as near-duplicates
+    pub duplicate_threshold: f64,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self {
+            rules: LintRule::ALL.to_vec(),
+            ambiguous_terms: [
+                "fast",
+                "quickly",
+                "user-friendly",
+                "easy",
+                "simple",
+                "adequate",
+                "sufficient",
+                "robust",
+                "flexible",
+                "approximately",
+                "as appropriate",
+                "as needed",
+                "if possible",
+                "and so on",
+                "etc.",
+            ]
+            .map(|x| x.to_string())
+            .to_vec(),
+            weak_modals: ["should", "may", "might", "could"]
+                .map(|x| x.to_string())
+                .to_vec(),
+            duplicate_threshold: 0.8,
+        }
+    }
+}
+
+/// A finding of the lint in a requirement
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LintFinding {
+    /// the identifier of the requirement
+    pub requirement: String,
+    /// the line (1-based) of the feature file
+    pub line: usize,
+    pub rule: LintRule,
+    pub message: String,
+}
+
+/// The regular expression matching each of `terms` as whole words and ignoring case
+fn whole_words(terms: &[String]) -> Vec<(&str, Regex)> {
+    terms
+        .iter()
+        .filter_map(|term| {
+            Regex::new(&format!(r"(?i)(?:^|\W){}(?:\W|$)", regex::escape(term)))
+                .ok()
+                .map(|regex| (term.as_str(), regex))
+        })
+        .collect()
+}
+
+/// The first of `terms` (from [`whole_words`]) occurring in `text`
+fn find_term<'a>(text: &str, terms: &[(&'a str, Regex)]) -> Option<&'a str> {
+    terms
+        .iter()
+        .find(|(_, regex)| regex.is_match(text))
+        .map(|(term, _)| *term)
+}
+
+/// The first passive voice without an actor (`by ...` in the same sentence) in `text`, if any
+fn find_passive(text: &str) -> Option<&str> {
+    lazy_static! {
+        static ref PASSIVE: Regex = Regex::new(
+            r"(?i)\b(?:is|are|was|were|be|been|being)\s+(?:\w+ed|given|written|taken|seen|chosen|shown|done|made|sent|built|kept|found|held|known|drawn)\b"
+        )
+        .unwrap();
+        static ref BY: Regex = Regex::new(r"(?i)\bby\b").unwrap();
+    }
+    text.split(['.', ';', '!', '?']).find_map(|sentence| {
+        let found = PASSIVE.find(sentence)?;
+        (!BY.is_match(&sentence[found.end()..])).then(|| found.as_str())
+    })
+}
+
+/// Lints the wording of `text`, at `line` of requirement `requirement`, where `is_title` is whether
+/// it is the title of the requirement, and `ambiguous_terms` and `weak_modals` are compiled from
+/// `config`
+fn lint_text(
+    requirement: &str,
+    line: usize,
+    text: &str,
+    is_title: bool,
+    config: &LintConfig,
+    ambiguous_terms: &[(&str, Regex)],
+    weak_modals: &[(&str, Regex)],
+) -> Vec<LintFinding> {
+    lazy_static! {
+        static ref JOINED: Regex = Regex::new(r"(?i)\b(?:and|or)\b").unwrap();
+    }
+    let finding = |rule, message| LintFinding {
+        requirement: requirement.to_string(),
+        line,
+        rule,
+        message,
+    };
+    let mut findings = vec![];
+    for rule in &config.rules {
+        match rule {
+            LintRule::AmbiguousTerm => {
+                if let Some(term) = find_term(text, ambiguous_terms) {
+                    findings.push(finding(
+                        *rule,
+                        format!("\"{term}\" is ambiguous, use a measurable criterion"),
+                    ));
+                }
+            }
+            LintRule::WeakModal => {
+                if let Some(modal) = find_term(text, weak_modals) {
+                    findings.push(finding(
+                        *rule,
+                        format!("\"{modal}\" is not binding, use \"must\""),
+                    ));
+                }
+            }
+            LintRule::PassiveVoice => {
+                if let Some(passive) = find_passive(text) {
+                    findings.push(finding(
+                        *rule,
+                        format!(
+                            "\"{passive}\" is passive without an actor, state who or what acts"
+                        ),
+                    ));
+                }
+            }
+            LintRule::Compound => {
+                let joined = if text.to_lowercase().contains("and/or") {
+                    Some("and/or".to_string())
+                } else if is_title {
+                    JOINED.find(text).map(|x| x.as_str().to_lowercase())
+                } else {
+                    None
+                };
+                if let Some(joined) = joined {
+                    findings.push(finding(
+                        *rule,
+                        format!("\"{joined}\" joins several requirements, split them"),
+                    ));
+                }
+            }
+        }
+    }
+    findings
+}
+
+/// The steps of every scenario, background and rule of `feature`
+fn steps(feature: &Feature) -> Vec<&Step> {
+    let backgrounds = feature
+        .background
+        .iter()
+        .chain(feature.rules.iter().flat_map(|rule| rule.background.iter()))
+        .flat_map(|background| background.steps.iter());
+    let scenarios = scenarios(feature).flat_map(|scenario| scenario.steps.iter());
+    backgrounds.chain(scenarios).collect()
+}
+
+/// Lints the wording of the title, description and steps of every requirement with the rules of `config`
+pub fn lint(documents: &Documents, config: &LintConfig) -> Vec<LintFinding> {
+    let ambiguous_terms = whole_words(&config.ambiguous_terms);
+    let weak_modals = whole_words(&config.weak_modals);
+    let lint_text = |requirement: &str, line, text: &str, is_title| {
+        lint_text(
+            requirement,
+            line,
+            text,
+            is_title,
+            config,
+            &ambiguous_terms,
+            &weak_modals,
+        )
+    };
+
+    let mut findings = vec![];
+    for (id, feature) in documents.features() {
+        let content = &documents.requirements()[id];
+        let title = feature
+            .name
+            .split_once(" - ")
+            .map(|(_, title)| title)
+            .unwrap_or_default();
+        findings.extend(lint_text(id, feature.position.line, title, true));
+
+        // the description follows the line of the feature
+        let lines = content.lines().collect::<Vec<_>>();
+        let mut next = feature.position.line;
+        for line in feature.description.iter().flat_map(|x| x.lines()) {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let number = lines
+                .iter()
+                .enumerate()
+                .skip(next)
+                .find(|(_, x)| x.trim() == line)
+                .map(|(index, _)| index + 1)
+                .unwrap_or(next);
+            next = number;
+            findings.extend(lint_text(id, number, line, false));
+        }
+
+        for step in steps(&feature) {
+            findings.extend(lint_text(id, step.position.line, &step.value, false));
+        }
+    }
+    findings
+}
+
+/// Renders the findings of the lint in markdown
+pub fn lint_to_markdown(findings: &[LintFinding]) -> String {
+    let mut result = "# Lint of requirements\n\n".to_string();
+    if findings.is_empty() {
+        result.push_str("There are no findings.\n");
+        return result;
+    }
+    result.push_str("| Requirement | Line | Rule | Finding |\n");
+    result.push_str("|-------------|------|------|---------|\n");
+    for finding in findings {
+        result.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            finding.requirement,
+            finding.line,
+            finding.rule,
+            cell(&finding.message)
+        ));
+    }
+    result
+}
@@ -1,7 +1,11 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::ExitCode;
+use std::time::SystemTime;
 
-use clap::Parser;
+use chrono::{DateTime, NaiveDate, Utc};
+use clap::{Parser, Subcommand, ValueEnum};
+use indexmap::IndexMap;
+use serde::Serialize;
 
 use documentation_as_code_gxp::*;
 
@@ -10,25 +14,592 @@ use documentation_as_code_gxp::*;
 struct Args {
     #[arg(short, long)]
     path: String,
+
+    /// JUnit XML or Cucumber JSON file with test results, used as verification evidence; repeat it
+    /// for several files
+    #[arg(short, long, global = true)]
+    evidence: Vec<PathBuf>,
+
+    /// Checks that the documentation passes a gate, e.g. `release`: no blocking open deviations and
+    /// no documents or items in draft or in review
+    #[arg(long, value_enum)]
+    gate: Option<Gate>,
+
+    /// Same as `--gate release`
+    #[arg(long)]
+    release: bool,
+
+    /// Checks that every commit in this range (e.g. `origin/main..HEAD`) changing the documentation
+    /// references a change request and, if approvers are configured, is approved by one of them
+    #[arg(long)]
+    change_control: Option<String>,
+
+    /// Reads the documentation at this revision (e.g. a tag or a commit) of its git repository,
+    /// instead of from the file system
+    #[arg(long)]
+    rev: Option<String>,
+
+    /// Attributes each item to the last commit modifying it, using the git repository of the documentation
+    #[arg(long, global = true)]
+    attribution: bool,
+
+    /// Checks the revision history table of each document against the git tags changing it, and
+    /// adds the revision history of documents without one, from git, to the summary report
+    #[arg(long, global = true)]
+    revision_history: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
 }
 
-fn main() -> ExitCode {
-    let args = Args::parse();
-    let project = PathBuf::from(args.path);
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Renders the trace graph of the documentation
+    Graph {
+        #[arg(short, long, value_enum)]
+        format: GraphFormat,
+
+        /// Only render items that trace, directly or indirectly, to this requirement
+        #[arg(short, long)]
+        requirement: Option<String>,
+    },
+    /// Reports which steps of each scenario are automated by step definitions
+    Automation {
+        /// Source files, or directories, with step definitions (Rust's cucumber, behave or cucumber-js)
+        #[arg(short, long, num_args = 1.., required = true)]
+        steps: Vec<PathBuf>,
+    },
+    /// Generates the validation summary report of the documentation and evidence
+    Summary {
+        #[arg(short, long, value_enum, default_value = "markdown")]
+        format: SummaryFormat,
+    },
+    /// Compares the items, trace and coverage of the documentation between two git revisions
+    Diff {
+        /// The revision before the changes, e.g. a tag or a commit
+        before: String,
+        /// The revision after the changes
+        after: String,
+
+        #[arg(short, long, value_enum, default_value = "markdown")]
+        format: DiffFormat,
+    },
+    /// Lists the requirements, risks and designs affected by changed items, and the tests to re-execute
+    Impact {
+        /// The changed items, e.g. `DS-1 FS-2`
+        items: Vec<String>,
+
+        /// Uses the items changed since this revision (e.g. a tag or a commit) of the git repository
+        #[arg(long, required_unless_present = "items")]
+        since: Option<String>,
+
+        #[arg(short, long, value_enum, default_value = "markdown")]
+        format: ReportFormat,
+    },
+    /// Registers new identifiers in, and marks removed identifiers as removed in, the ledger of
+    /// identifiers (identifiers.json) of the documentation
+    Ledger,
+    /// Generates an Ed25519 key to sign baselines, writing the private key to a file and
+    /// printing the public key
+    Keygen {
+        /// The file to write the private key to
+        key: PathBuf,
+    },
+    /// Signs a baseline with an Ed25519 private key, adding the signature to the baseline's
+    /// signatures file (e.g. `baseline.signatures.json` for `baseline.json`)
+    Sign {
+        /// The file of the baseline
+        baseline: PathBuf,
+
+        /// The file with the private key of the signer
+        #[arg(long)]
+        key: PathBuf,
+
+        /// The name of the signer
+        #[arg(long)]
+        signer: String,
+
+        /// The meaning of the signature: authored, reviewed or approved
+        #[arg(long)]
+        meaning: Meaning,
+    },
+    /// Checks that a baseline is signed with every required meaning, with trusted keys
+    VerifySignatures {
+        /// The file of the baseline
+        baseline: PathBuf,
+    },
+    /// Lists the documents and requirements overdue or due for their periodic review
+    ReviewStatus {
+        /// Also lists those due within this number of days
+        #[arg(long, default_value = "30")]
+        within: u32,
+
+        #[arg(short, long, value_enum, default_value = "markdown")]
+        format: ReportFormat,
+    },
+    /// Lints the wording of the requirements, e.g. ambiguous terms, with the rules of `lint` of the
+    /// configuration, failing when there are findings
+    Lint {
+        #[arg(short, long, value_enum, default_value = "markdown")]
+        format: ReportFormat,
+        /// Reports near-duplicate requirements, scenarios and items instead, from
+        /// `duplicate_threshold` of `lint` of the configuration
+        #[arg(long)]
+        duplicates: bool,
+    },
+    /// Creates or verifies a baseline: the SHA-256 of every document, feature file and item
+    Baseline {
+        #[command(subcommand)]
+        command: BaselineCommand,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum BaselineCommand {
+    /// Prints the baseline of the documentation, e.g. to be committed and approved for a release
+    Create {
+        /// The version of the specification, e.g. `1.2.0`
+        version: String,
+    },
+    /// Checks that the documentation matches exactly an approved baseline
+    Verify {
+        /// The file of the baseline
+        baseline: PathBuf,
+    },
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Gate {
+    Release,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum GraphFormat {
+    Dot,
+    Mermaid,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum SummaryFormat {
+    Markdown,
+    Html,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum DiffFormat {
+    Markdown,
+    Json,
+    /// redline (tracked changes) of every changed item
+    Html,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum ReportFormat {
+    Markdown,
+    Json,
+}
+
+#[derive(Serialize)]
+struct Output<'a> {
+    #[serde(flatten)]
+    documents: &'a Documents,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    verification: Option<IndexMap<String, Verification>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attribution: Option<Attributions>,
+}
+
+fn print_errors(Error(errors): Error) -> ExitCode {
+    for error in errors {
+        eprintln!("ERROR: {error}");
+    }
+    ExitCode::FAILURE
+}
+
+/// The documentation at `revision` of its git repository, with its configuration at that revision
+fn get_documents_at(project: &Path, revision: &str) -> Result<Documents, Error> {
+    let source = Source::git(project, revision)?;
+    let config = get_config(project, &source)?;
+    get_documents(project.to_path_buf(), &source, &config)
+}
+
+/// The documentation, read and checked, with the configuration and evidence used by subcommands
+struct Context {
+    project: PathBuf,
+    source: Source,
+    config: Config,
+    documents: Documents,
+    executions: Option<Vec<Execution>>,
+    attributions: Option<Attributions>,
+    revision_histories: Option<RevisionHistories>,
+    ledger: Option<Ledger>,
+    today: NaiveDate,
+}
+
+impl Context {
+    /// Reads and checks the documentation as requested by `args`, printing warnings
+    fn try_new(args: &Args) -> Result<Self, Error> {
+        let project = PathBuf::from(&args.path);
+        let source = match &args.rev {
+            Some(revision) => Source::git(&project, revision)?,
+            None => Source::FileSystem,
+        };
+        let config = get_config(&project, &source)?;
+        let documents = get_documents(project.clone(), &source, &config)?;
+
+        let executions = if args.evidence.is_empty() {
+            None
+        } else {
+            Some(get_executions(&args.evidence)?)
+        };
+        let attributions = if args.attribution {
+            Some(get_attributions(&project, &source, &documents)?)
+        } else {
+            None
+        };
+        let revision_histories = if args.revision_history {
+            Some(get_revision_histories(&project, &source, &documents)?)
+        } else {
+            None
+        };
+        let ledger = get_ledger(&project, &source)?;
+        let today = DateTime::<Utc>::from(SystemTime::now()).date_naive();
+
+        let mut errors = vec![];
+        if let Some(ledger) = &ledger {
+            // the ledger command is how the ledger is brought up to date
+            if !matches!(args.command, Some(Command::Ledger)) {
+                errors.extend(check_ledger(&documents, ledger));
+            }
+        }
+        if let Some(histories) = &revision_histories {
+            errors.extend(check_revision_histories(&documents, histories));
+        }
+        if let Some(executions) = &executions {
+            errors.extend(check_failed_executions(&documents, executions));
+        }
+        if args.release || args.gate == Some(Gate::Release) {
+            errors.extend(check_release(&documents, config.max_open_severity));
+            errors.extend(check_release_status(&documents));
+            errors.extend(check_release_scenarios(&documents));
+            errors.extend(check_placeholders(&documents, &config));
+            match get_review_status(&project, &source, &documents, &config, today) {
+                Ok(statuses) => errors.extend(check_review_status(&statuses)),
+                Err(Error(new_errors)) => errors.extend(new_errors),
+            }
+        } else {
+            for warning in get_status_warnings(&documents)
+                .into_iter()
+                .chain(get_scenario_warnings(&documents))
+                .chain(check_placeholders(&documents, &config))
+            {
+                eprintln!("WARNING: {warning}");
+            }
+        }
+        for warning in get_glossary_warnings(&documents) {
+            eprintln!("WARNING: {warning}");
+        }
+        if let Some(range) = &args.change_control {
+            errors.extend(check_change_control(&project, range, &config));
+        }
+        if !errors.is_empty() {
+            return Err(Error(errors));
+        }
+
+        Ok(Self {
+            project,
+            source,
+            config,
+            documents,
+            executions,
+            attributions,
+            revision_histories,
+            ledger,
+            today,
+        })
+    }
+
+    fn verification(&self) -> Option<IndexMap<String, Verification>> {
+        self.executions
+            .as_ref()
+            .map(|executions| verify(&self.documents, executions))
+    }
+}
+
+fn print_documents(context: Context) -> Result<ExitCode, Error> {
+    let output = Output {
+        documents: &context.documents,
+        verification: context.verification(),
+        attribution: context.attributions,
+    };
+    println!("{}", serde_json::to_string_pretty(&output).unwrap());
+    Ok(ExitCode::SUCCESS)
+}
+
+fn diff(project: &Path, before: &str, after: &str, format: DiffFormat) -> Result<ExitCode, Error> {
+    let before_documents = get_documents_at(project, before)?;
+    let after_documents = get_documents_at(project, after)?;
+    let diff = Diff::new(&before_documents, &after_documents);
+    match format {
+        DiffFormat::Markdown => print!("{}", diff.to_markdown(before, after)),
+        DiffFormat::Json => println!("{}", serde_json::to_string_pretty(&diff).unwrap()),
+        DiffFormat::Html => print!(
+            "{}",
+            redline(&before_documents, &after_documents, before, after)
+        ),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn keygen(key: &Path) -> Result<ExitCode, Error> {
+    let (private_key, public_key) = generate_key();
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    // the private key must only be readable by its owner
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options
+        .open(key)
+        .and_then(|mut file| std::io::Write::write_all(&mut file, private_key.as_bytes()))
+        .map_err(|error| {
+            format!(
+                "Can't write the private key to {}: {error}",
+                key.to_string_lossy()
+            )
+        })?;
+    println!("{public_key}");
+    Ok(ExitCode::SUCCESS)
+}
+
+fn sign_baseline(
+    baseline: &Path,
+    key: &Path,
+    signer: &str,
+    meaning: Meaning,
+) -> Result<ExitCode, Error> {
+    get_baseline(baseline)?;
+    let content = Source::FileSystem.read_file(baseline)?;
+    let key = Source::FileSystem.read_file(key)?;
+    let path = signatures_path(baseline);
+    let mut signatures = get_signatures(&path)?;
+    signatures.push(sign(&content, &key, signer, meaning)?);
+
+    let content = serde_json::to_string_pretty(&signatures).unwrap() + "\n";
+    std::fs::write(&path, content)
+        .map_err(|error| format!("Can't write {}: {error}", path.to_string_lossy()))?;
+    Ok(ExitCode::SUCCESS)
+}
+
+fn graph(
+    context: &Context,
+    format: GraphFormat,
+    requirement: Option<&str>,
+) -> Result<ExitCode, Error> {
+    let mut graph = Graph::new(&context.documents);
+    if let Some(requirement) = requirement {
+        graph = graph.neighbourhood(requirement)?;
+    }
+    match format {
+        GraphFormat::Dot => print!("{}", graph.to_dot()),
+        GraphFormat::Mermaid => print!("{}", graph.to_mermaid()),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn automation(context: &Context, steps: &[PathBuf]) -> Result<ExitCode, Error> {
+    let definitions = get_step_definitions(steps)?;
+    let automation = get_automation(&context.documents, &definitions);
+    println!("{}", serde_json::to_string_pretty(&automation).unwrap());
+    Ok(ExitCode::SUCCESS)
+}
 
-    let documents = match get_documents(project) {
-        Ok(documents) => documents,
-        Err(Error(errors)) => {
-            for error in errors {
-                eprintln!("ERROR: {error}");
+fn summary_report(context: &Context, format: SummaryFormat) -> Result<ExitCode, Error> {
+    let verification = context.verification();
+    let report = summary(
+        &context.documents,
+        verification.as_ref(),
+        context.attributions.as_ref(),
+        context.revision_histories.as_ref(),
+    );
+    match format {
+        SummaryFormat::Markdown => print!("{report}"),
+        SummaryFormat::Html => print!("{}", to_html(&report, &get_glossary(&context.documents))),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn impact_report(
+    context: &Context,
+    items: &[String],
+    since: Option<&str>,
+    format: ReportFormat,
+) -> Result<ExitCode, Error> {
+    let mut items = items.to_vec();
+    if let Some(since) = since {
+        let before = get_documents_at(&context.project, since)?;
+        let diff = Diff::new(&before, &context.documents);
+        items.extend(changed_items(&context.documents, &diff));
+    }
+    let impact = impact(&context.documents, &items)?;
+    match format {
+        ReportFormat::Markdown => print!("{}", impact.to_markdown()),
+        ReportFormat::Json => println!("{}", serde_json::to_string_pretty(&impact).unwrap()),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn ledger(context: Context) -> Result<ExitCode, Error> {
+    if let Some(revision) = context.source.revision() {
+        return Err(Error(vec![format!(
+            "The ledger must be updated from the documentation in the file system, but it is read at revision {revision}"
+        )]));
+    }
+    let (ledger, changes) = update_ledger(&context.documents, context.ledger.unwrap_or_default())?;
+    let content = serde_json::to_string_pretty(&ledger).unwrap() + "\n";
+    std::fs::write(context.project.join(LEDGER_FILE), content)
+        .map_err(|error| format!("Can't write {LEDGER_FILE}: {error}"))?;
+    for change in changes {
+        println!("{change}");
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn baseline(context: &Context, command: &BaselineCommand) -> Result<ExitCode, Error> {
+    match command {
+        BaselineCommand::Create { version } => {
+            let current = Baseline::try_new(
+                &context.project,
+                &context.source,
+                &context.documents,
+                version,
+            )?;
+            println!("{}", serde_json::to_string_pretty(&current).unwrap())
+        }
+        BaselineCommand::Verify { baseline } => {
+            let current =
+                Baseline::try_new(&context.project, &context.source, &context.documents, "")?;
+            let baseline = get_baseline(baseline)?;
+            let errors = baseline.verify(&current);
+            if !errors.is_empty() {
+                return Err(Error(errors));
             }
-            return ExitCode::FAILURE;
+            println!("The documentation matches baseline {}", baseline.version);
         }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn review_status(context: &Context, within: u32, format: ReportFormat) -> Result<ExitCode, Error> {
+    let statuses = get_review_status(
+        &context.project,
+        &context.source,
+        &context.documents,
+        &context.config,
+        context.today,
+    )?
+    .into_iter()
+    .filter(|status| status.days <= within.into())
+    .collect::<Vec<_>>();
+    match format {
+        ReportFormat::Markdown => print!("{}", review_status_to_markdown(&statuses, within)),
+        ReportFormat::Json => println!("{}", serde_json::to_string_pretty(&statuses).unwrap()),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn lint_report(
+    context: &Context,
+    format: ReportFormat,
+    duplicates: bool,
+) -> Result<ExitCode, Error> {
+    let is_empty = if duplicates {
+        let duplicates =
+            find_duplicates(&context.documents, context.config.lint.duplicate_threshold);
+        match format {
+            ReportFormat::Markdown => print!("{}", duplicates_to_markdown(&duplicates)),
+            ReportFormat::Json => {
+                println!("{}", serde_json::to_string_pretty(&duplicates).unwrap())
+            }
+        }
+        duplicates.is_empty()
+    } else {
+        let findings = lint(&context.documents, &context.config.lint);
+        match format {
+            ReportFormat::Markdown => print!("{}", lint_to_markdown(&findings)),
+            ReportFormat::Json => println!("{}", serde_json::to_string_pretty(&findings).unwrap()),
+        }
+        findings.is_empty()
     };
+    Ok(if is_empty {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
 
-    let result = serde_json::to_string_pretty(&documents).unwrap();
+fn verify_baseline_signatures(context: &Context, baseline: &Path) -> Result<ExitCode, Error> {
+    let current = Baseline::try_new(&context.project, &context.source, &context.documents, "")?;
+    // a valid signature is meaningless for documentation that no longer matches the baseline
+    let mut errors = get_baseline(baseline)?.verify(&current);
 
-    println!("{result}");
+    let content = Source::FileSystem.read_file(baseline)?;
+    let signatures = get_signatures(&signatures_path(baseline))?;
+    let public_keys = get_public_keys(&context.project, &context.source)?;
+    errors.extend(verify_signatures(
+        &content,
+        &signatures,
+        &public_keys,
+        &context.config.required_signatures,
+    ));
+    if !errors.is_empty() {
+        return Err(Error(errors));
+    }
+    println!("The documentation matches the baseline, which is signed with every required meaning");
+    Ok(ExitCode::SUCCESS)
+}
 
-    ExitCode::SUCCESS
+fn run(args: &Args) -> Result<ExitCode, Error> {
+    let project = PathBuf::from(&args.path);
+    match &args.command {
+        None => print_documents(Context::try_new(args)?),
+        Some(Command::Diff {
+            before,
+            after,
+            format,
+        }) => diff(&project, before, after, *format),
+        Some(Command::Keygen { key }) => keygen(key),
+        Some(Command::Sign {
+            baseline,
+            key,
+            signer,
+            meaning,
+        }) => sign_baseline(baseline, key, signer, *meaning),
+        Some(Command::Graph {
+            format,
+            requirement,
+        }) => graph(&Context::try_new(args)?, *format, requirement.as_deref()),
+        Some(Command::Automation { steps }) => automation(&Context::try_new(args)?, steps),
+        Some(Command::Summary { format }) => summary_report(&Context::try_new(args)?, *format),
+        Some(Command::Impact {
+            items,
+            since,
+            format,
+        }) => impact_report(&Context::try_new(args)?, items, since.as_deref(), *format),
+        Some(Command::Ledger) => ledger(Context::try_new(args)?),
+        Some(Command::Baseline { command }) => baseline(&Context::try_new(args)?, command),
+        Some(Command::ReviewStatus { within, format }) => {
+            review_status(&Context::try_new(args)?, *within, *format)
+        }
+        Some(Command::Lint { format, duplicates }) => {
+            lint_report(&Context::try_new(args)?, *format, *duplicates)
+        }
+        Some(Command::VerifySignatures { baseline }) => {
+            verify_baseline_signatures(&Context::try_new(args)?, baseline)
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    run(&args).unwrap_or_else(print_errors)
 }
@@ -0,0 +1,94 @@
+use pulldown_cmark::{Event, Options, Parser, Tag};
+
+/// The text of the markdown `text` outside of code, where elements are separated by spaces
+pub(crate) fn prose(text: &str) -> String {
+    let mut result = String::new();
+    let mut in_code = false;
+    for event in Parser::new(text) {
+        match event {
+            Event::Start(Tag::CodeBlock(_)) => in_code = true,
+            Event::End(Tag::CodeBlock(_)) => in_code = false,
+            Event::Text(inner) if !in_code => result.push_str(&inner),
+            _ => result.push(' '),
+        }
+    }
+    result
+}
+
+/// The rows (the header first) of each table of the markdown `text`
+pub(crate) fn parse_tables(text: &str) -> Vec<Vec<Vec<String>>> {
+    let mut tables: Vec<Vec<Vec<String>>> = vec![];
+    let mut in_cell = false;
+    Parser::new_ext(text, Options::ENABLE_TABLES).for_each(|event| match event {
+        Event::Start(Tag::Table(_)) => tables.push(vec![]),
+        Event::Start(Tag::TableHead) | Event::Start(Tag::TableRow) => {
+            tables.last_mut().unwrap().push(vec![])
+        }
+        Event::Start(Tag::TableCell) => {
+            in_cell = true;
+            let row = tables.last_mut().unwrap().last_mut().unwrap();
+            row.push(String::new());
+        }
+        Event::End(Tag::TableCell) => in_cell = false,
+        Event::Text(inner) | Event::Code(inner) if in_cell => {
+            let row = tables.last_mut().unwrap().last_mut().unwrap();
+            row.last_mut().unwrap().push_str(&inner);
+        }
+        _ => {}
+    });
+    tables
+}
+
+/// The rows (the header first) of the first table of the markdown `text` with every column of
+/// `columns`, ignoring case, with the index of each of these columns
+pub(crate) fn find_table<const N: usize>(
+    text: &str,
+    columns: [&str; N],
+) -> Option<(Vec<Vec<String>>, [usize; N])> {
+    parse_tables(text).into_iter().find_map(|rows| {
+        let header = rows.first()?;
+        let mut indexes = [0; N];
+        for (index, column) in indexes.iter_mut().zip(columns) {
+            *index = header
+                .iter()
+                .position(|x| x.trim().eq_ignore_ascii_case(column))?;
+        }
+        Some((rows, indexes))
+    })
+}
+
+/// Escapes `|` and joins the lines of `text` so that it can be used in a cell of a markdown table
+pub(crate) fn cell(text: &str) -> String {
+    text.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace('|', "\\|")
+}
+
+/// The lines (with their 1-based number) of the body of an item starting at line `start`, i.e.
+/// after its heading, without its trace and outside of code blocks
+pub(crate) fn body(item: &str, start: usize) -> Vec<(usize, &str)> {
+    let mut in_trace = false;
+    let mut in_code = false;
+    item.lines()
+        .enumerate()
+        .skip(1)
+        .filter(|(_, line)| {
+            if line.trim_start().starts_with("```") {
+                in_code = !in_code;
+                return false;
+            }
+            if in_code {
+                return false;
+            }
+            if let Some(heading) = line.strip_prefix("### ") {
+                in_trace = heading.trim() == "Trace";
+                return false;
+            }
+            !in_trace
+        })
+        .map(|(index, line)| (start + index, line))
+        .collect()
+}
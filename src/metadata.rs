@@ -0,0 +1,130 @@
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Lifecycle status of a document
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DocumentStatus {
+    Draft,
+    #[serde(rename = "in review")]
+    InReview,
+    Approved,
+    Effective,
+}
+
+impl DocumentStatus {
+    /// Whether the document or item is still in progress, i.e. not part of the validated state
+    pub fn is_in_progress(&self) -> bool {
+        matches!(self, Self::Draft | Self::InReview)
+    }
+}
+
+impl FromStr for DocumentStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "draft" => Ok(Self::Draft),
+            "in review" => Ok(Self::InReview),
+            "approved" => Ok(Self::Approved),
+            "effective" => Ok(Self::Effective),
+            _ => Err(format!(
+                "status must be one of draft, in review, approved or effective, but \"{s}\" is not"
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for DocumentStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Draft => write!(f, "draft"),
+            Self::InReview => write!(f, "in review"),
+            Self::Approved => write!(f, "approved"),
+            Self::Effective => write!(f, "effective"),
+        }
+    }
+}
+
+/// Document-level control information, declared in the YAML front matter of a document
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Metadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<DocumentStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub approvers: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_date: Option<NaiveDate>,
+    /// date of the next periodic review of the document
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_review: Option<NaiveDate>,
+}
+
+/// Splits the YAML front matter, delimited by lines `---`, from the start of `text`.
+/// Returns the metadata, if any, and the byte offset of the rest of the text.
+pub(crate) fn split_front_matter(
+    text: &str,
+    file_name: &str,
+) -> Result<(Option<Metadata>, usize), Vec<String>> {
+    let Some(start) = ["---\n", "---\r\n"]
+        .into_iter()
+        .find(|delimiter| text.starts_with(delimiter))
+        .map(|delimiter| delimiter.len())
+    else {
+        return Ok((None, 0));
+    };
+
+    let mut offset = start;
+    let mut end = None;
+    for line in text[start..].split_inclusive('\n') {
+        if line.trim_end() == "---" {
+            end = Some((offset, offset + line.len()));
+            break;
+        }
+        offset += line.len();
+    }
+    let Some((end, body)) = end else {
+        return Err(vec![format!(
+            "The front matter of \"{file_name}\" must end with a line \"---\", but it does not"
+        )]);
+    };
+
+    let yaml = &text[start..end];
+    let metadata = if yaml.trim().is_empty() {
+        Metadata::default()
+    } else {
+        serde_yaml::from_str::<Metadata>(yaml).map_err(|error| {
+            vec![format!(
+                "The front matter of \"{file_name}\" must be valid, but it is not: {error}"
+            )]
+        })?
+    };
+
+    let mut errors = vec![];
+    if metadata.status == Some(DocumentStatus::Effective) && metadata.effective_date.is_none() {
+        errors.push(format!(
+            "Effective documents must have an effective_date, but \"{file_name}\" does not"
+        ));
+    }
+    if let (Some(effective), Some(review)) = (metadata.effective_date, metadata.next_review) {
+        if review <= effective {
+            errors.push(format!(
+                "The next_review of a document must be after its effective_date, but the one of \"{file_name}\" is not"
+            ));
+        }
+    }
+    if errors.is_empty() {
+        Ok((Some(metadata), body))
+    } else {
+        Err(errors)
+    }
+}
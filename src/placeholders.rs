@@ -0,0 +1,71 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use super::config::Config;
+use super::markdown::{body, prose};
+use super::specification::Documents;
+
+/// The placeholders reported by default, e.g. in items not yet written
+pub(crate) fn default_placeholders() -> Vec<String> {
+    ["TBD", "TODO", "XXX", "lorem ipsum"]
+        .map(|x| x.to_string())
+        .to_vec()
+}
+
+/// Checks that no item of the documentation is empty, contains a placeholder of `placeholders`
+/// of `config`, or contains fewer words than `min_item_words` of `config`
+pub fn check_placeholders(documents: &Documents, config: &Config) -> Vec<String> {
+    lazy_static! {
+        static ref WORD: Regex = Regex::new(r"\w+").unwrap();
+    }
+    let placeholders = config
+        .placeholders
+        .iter()
+        .filter_map(|placeholder| {
+            Regex::new(&format!(
+                r"(?i)(?:^|\W){}(?:\W|$)",
+                regex::escape(placeholder)
+            ))
+            .ok()
+            .map(|regex| (placeholder, regex))
+        })
+        .collect::<Vec<_>>();
+
+    let mut errors = vec![];
+    for (type_, document) in documents.documents() {
+        let file = type_.file_name();
+        let spans = document.spans();
+        for (id, item) in document.items() {
+            let start = *spans[&id].start();
+            let lines = body(&item, start);
+
+            for (number, line) in &lines {
+                for (placeholder, regex) in &placeholders {
+                    if regex.is_match(&prose(line)) {
+                        errors.push(format!(
+                            "Items must not contain placeholders, but {id} ({file}:{number}) contains \"{placeholder}\""
+                        ));
+                    }
+                }
+            }
+
+            let text = lines
+                .iter()
+                .map(|(_, line)| *line)
+                .collect::<Vec<_>>()
+                .join("\n");
+            let words = WORD.find_iter(&prose(&text)).count();
+            if words == 0 {
+                errors.push(format!(
+                    "Items must not be empty, but {id} ({file}:{start}) is"
+                ));
+            } else if words < config.min_item_words {
+                errors.push(format!(
+                    "Items must contain at least {} words, but {id} ({file}:{start}) contains {words}",
+                    config.min_item_words
+                ));
+            }
+        }
+    }
+    errors
+}
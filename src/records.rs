@@ -0,0 +1,278 @@
+use std::path::Path;
+
+use chrono::NaiveDate;
+use indexmap::IndexMap;
+use lazy_static::lazy_static;
+use pulldown_cmark::{Event, HeadingLevel, Parser, Tag};
+use regex::Regex;
+use serde::Serialize;
+
+use super::files::Source;
+use super::markdown::parse_tables;
+use super::specification::{extract_identifier, scenarios, DocumentType, Documents};
+
+/// The directory, relative to the documentation, with the executed test records
+pub const TEST_RECORDS_DIRECTORY: &str = "test_records";
+
+/// A step of an executed test
+#[derive(Debug, Clone, Serialize)]
+pub struct TestRecordStep {
+    pub step: String,
+    pub expected: String,
+    pub actual: String,
+    pub pass: bool,
+}
+
+/// The record of a manual execution of a scenario
+#[derive(Debug, Clone, Serialize)]
+pub struct TestRecord {
+    pub text: String, // markdown
+    pub requirement: String,
+    pub scenario: String,
+    pub test: String,
+    pub tester: String,
+    pub date: NaiveDate,
+    pub environment: String,
+    pub steps: Vec<TestRecordStep>,
+    pub signature: String,
+}
+
+impl TestRecord {
+    /// Whether every step of the record passed
+    pub fn passed(&self) -> bool {
+        self.steps.iter().all(|step| step.pass)
+    }
+}
+
+pub type TestRecords = IndexMap<String, TestRecord>;
+
+fn parse_pass(value: &str) -> Option<bool> {
+    match value.trim().to_lowercase().as_str() {
+        "yes" | "pass" | "passed" => Some(true),
+        "no" | "fail" | "failed" => Some(false),
+        _ => None,
+    }
+}
+
+fn parse_record(
+    name: &str,
+    text: String,
+    errors: &mut Vec<String>,
+) -> Option<(String, TestRecord)> {
+    lazy_static! {
+        static ref FIELD: Regex = Regex::new(r"^(?P<key>[A-Za-z]+):\s*(?P<value>.*?)\s*$").unwrap();
+    }
+    let initial_errors = errors.len();
+
+    let mut title = None;
+    Parser::new(&text).fold(false, |in_title, event| match event {
+        Event::Start(Tag::Heading(HeadingLevel::H1, _, _)) => true,
+        Event::Text(inner) if in_title => {
+            title.get_or_insert(inner.to_string());
+            true
+        }
+        Event::End(Tag::Heading(HeadingLevel::H1, _, _)) => false,
+        _ => in_title,
+    });
+    let id = match title.as_deref().and_then(extract_identifier) {
+        Some(id) if id.starts_with("TR-") => id.to_string(),
+        _ => {
+            errors.push(format!(
+                "\"{name}\" must start with a title of the form \"# TR-<id> - <title>\""
+            ));
+            return None;
+        }
+    };
+
+    let fields = text
+        .lines()
+        .filter_map(|line| FIELD.captures(line))
+        .map(|captures| {
+            (
+                captures["key"].to_lowercase(),
+                captures["value"].to_string(),
+            )
+        })
+        .collect::<IndexMap<_, _>>();
+    let mut field = |key: &str| -> String {
+        match fields.get(&key.to_lowercase()) {
+            Some(value) if !value.is_empty() => value.clone(),
+            _ => {
+                errors.push(format!(
+                    "\"{name}\" must contain a line \"{key}: <value>\", but it does not"
+                ));
+                String::new()
+            }
+        }
+    };
+    let requirement = field("Requirement");
+    let scenario = field("Scenario");
+    let test = field("Test");
+    let tester = field("Tester");
+    let date = field("Date");
+    let environment = field("Environment");
+    let signature = field("Signature");
+
+    let date = match NaiveDate::parse_from_str(&date, "%Y-%m-%d") {
+        Ok(date) => date,
+        Err(_) => {
+            if !date.is_empty() {
+                errors.push(format!(
+                    "\"{name}\" must contain a date of the form YYYY-MM-DD, but \"{date}\" is not"
+                ));
+            }
+            NaiveDate::default()
+        }
+    };
+
+    // other tables, e.g. of the equipment, are ignored
+    let rows = parse_tables(&text)
+        .into_iter()
+        .find(|rows| {
+            let header = rows.first().map(|row| {
+                row.iter()
+                    .map(|cell| cell.trim().to_lowercase())
+                    .collect::<Vec<_>>()
+            });
+            header.as_deref() == Some(&["step", "expected", "actual", "pass"].map(String::from))
+        })
+        .unwrap_or_default();
+    if rows.is_empty() {
+        errors.push(format!(
+            "\"{name}\" must contain a table with the columns \"Step | Expected | Actual | Pass\""
+        ));
+    } else if rows.len() < 2 {
+        errors.push(format!(
+            "\"{name}\" must contain at least one executed step, but it contains none"
+        ));
+    }
+    let steps = rows
+        .iter()
+        .skip(1)
+        .filter_map(|row| {
+            let pass = parse_pass(row.get(3).map(|x| x.as_str()).unwrap_or_default());
+            if pass.is_none() {
+                errors.push(format!(
+                    "\"{name}\" must state whether each step passed (yes or no), but step \"{}\" does not",
+                    row.first().map(|x| x.as_str()).unwrap_or_default()
+                ));
+            }
+            Some(TestRecordStep {
+                step: row.first()?.clone(),
+                expected: row.get(1)?.clone(),
+                actual: row.get(2)?.clone(),
+                pass: pass?,
+            })
+        })
+        .collect();
+
+    if errors.len() != initial_errors {
+        return None;
+    }
+    let record = TestRecord {
+        text,
+        requirement,
+        scenario,
+        test,
+        tester,
+        date,
+        environment,
+        steps,
+        signature,
+    };
+    Some((id, record))
+}
+
+/// Returns the executed test records in the directory `test_records` of `project`, if it exists.
+pub fn get_test_records(project: &Path, source: &Source, errors: &mut Vec<String>) -> TestRecords {
+    let path = project.join(TEST_RECORDS_DIRECTORY);
+    if !source.is_dir(&path) {
+        return Default::default();
+    }
+
+    let paths = match source.list_directory(path) {
+        Ok(paths) => paths,
+        Err(error) => {
+            errors.push(error);
+            return Default::default();
+        }
+    };
+
+    let mut records = TestRecords::new();
+    for path in paths
+        .into_iter()
+        .filter(|path| path.extension().unwrap_or_default() == "md")
+    {
+        let name = format!(
+            "{TEST_RECORDS_DIRECTORY}/{}",
+            path.file_name().unwrap().to_string_lossy()
+        );
+        let text = match source.read_file(&path) {
+            Ok(text) => text,
+            Err(error) => {
+                errors.push(error);
+                continue;
+            }
+        };
+        if let Some((id, record)) = parse_record(&name, text, errors) {
+            if records.insert(id.clone(), record).is_some() {
+                errors.push(format!(
+                    "Test records must have unique identifiers, but {id} is not"
+                ));
+            }
+        }
+    }
+    records
+}
+
+/// Checks that every test record is linked to an existing scenario and to a test traced to its requirement,
+/// and that every scenario tagged `@manual` has at least one test record.
+pub fn check_test_records(documents: &Documents, records: &TestRecords) -> Vec<String> {
+    let mut errors = vec![];
+    let tests = documents.document(DocumentType::Tests).trace();
+    let features = documents.features().collect::<IndexMap<_, _>>();
+
+    for (id, record) in records {
+        let Some(feature) = features.get(&record.requirement) else {
+            errors.push(format!(
+                "Test records must be linked to existing requirements, but {id} is linked to {}",
+                record.requirement
+            ));
+            continue;
+        };
+        if !scenarios(feature).any(|scenario| scenario.name == record.scenario) {
+            errors.push(format!(
+                "Test records must be linked to existing scenarios, but {id} is linked to \"{}\", which is not a scenario of {}",
+                record.scenario, record.requirement
+            ));
+        }
+        let is_traced = tests
+            .get(&record.test)
+            .map(|trace| trace.contains(&record.requirement))
+            .unwrap_or(false);
+        if !is_traced {
+            errors.push(format!(
+                "Test records must be linked to a test traced to their requirement, but {} of {id} is not traced to {}",
+                record.test, record.requirement
+            ));
+        }
+    }
+
+    for (requirement, feature) in &features {
+        let is_manual_feature = feature.tags.iter().any(|tag| tag == "manual");
+        for scenario in scenarios(feature) {
+            let is_manual = is_manual_feature || scenario.tags.iter().any(|tag| tag == "manual");
+            let has_record = records.values().any(|record| {
+                record.requirement == **requirement && record.scenario == scenario.name
+            });
+            if is_manual && !has_record {
+                errors.push(format!(
+                    "Manual scenarios must have an executed test record, but \"{}\" of {requirement} has none",
+                    scenario.name
+                ));
+            }
+        }
+    }
+
+    errors
+}
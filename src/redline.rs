@@ -0,0 +1,105 @@
+use indexmap::IndexMap;
+use pulldown_cmark::escape::escape_html;
+use similar::{ChangeTag, TextDiff};
+
+use super::diff::{is_modified, items};
+use super::specification::Documents;
+
+const STYLE: &str = "body { font-family: sans-serif; }
+.text { white-space: pre-wrap; font-family: monospace; border: 1px solid #d9d9d9; padding: 0.5em; }
+ins { background-color: #ccebc5; text-decoration: underline; }
+del { background-color: #fbb4ae; text-decoration: line-through; }";
+
+fn escape(text: &str) -> String {
+    let mut result = String::new();
+    escape_html(&mut result, text).unwrap();
+    result
+}
+
+/// Renders `before` and `after` as HTML, with deletions in `<del>` and insertions in `<ins>`, word by word
+fn redline_text(before: &str, after: &str) -> String {
+    // consecutive words with the same change are grouped
+    let mut groups = Vec::<(ChangeTag, String)>::new();
+    for change in TextDiff::from_words(before, after).iter_all_changes() {
+        match groups.last_mut() {
+            Some((tag, value)) if *tag == change.tag() => value.push_str(change.value()),
+            _ => groups.push((change.tag(), change.value().to_string())),
+        }
+    }
+    groups
+        .into_iter()
+        .map(|(tag, value)| {
+            let value = escape(&value);
+            match tag {
+                ChangeTag::Equal => value,
+                ChangeTag::Delete => format!("<del>{value}</del>"),
+                ChangeTag::Insert => format!("<ins>{value}</ins>"),
+            }
+        })
+        .collect()
+}
+
+/// Renders the items (or sections) removed, added and modified from `before` to `after`, by
+/// identifier (or heading), as HTML sections
+fn redline_changes(before: &IndexMap<String, String>, after: &IndexMap<String, String>) -> String {
+    let removed = before
+        .iter()
+        .filter(|(id, _)| !after.contains_key(*id))
+        .map(|(id, text)| (id, "removed", redline_text(text, "")));
+    let changed = after.iter().filter_map(|(id, text)| match before.get(id) {
+        None => Some((id, "added", redline_text("", text))),
+        Some(before) if is_modified(before, text) => {
+            Some((id, "modified", redline_text(before, text)))
+        }
+        _ => None,
+    });
+    removed
+        .chain(changed)
+        .map(|(id, change, text)| {
+            format!(
+                "<section>\n<h3>{} ({change})</h3>\n<div class=\"text\">{text}</div>\n</section>\n",
+                escape(id)
+            )
+        })
+        .collect()
+}
+
+/// Renders the changes of every item and section without identifier of the documentation from
+/// `before` to `after` as a redline (tracked changes) HTML document, titled with the revisions compared
+pub fn redline(
+    before: &Documents,
+    after: &Documents,
+    before_name: &str,
+    after_name: &str,
+) -> String {
+    let before_items = items(before);
+    let mut changes = items(after)
+        .iter()
+        .map(|(name, after_items)| (*name, redline_changes(&before_items[name], after_items)))
+        .collect::<IndexMap<_, _>>();
+    for ((type_, after), (_, before)) in after.documents().into_iter().zip(before.documents()) {
+        changes
+            .entry(type_.file_name())
+            .or_default()
+            .push_str(&redline_changes(before.sections(), after.sections()));
+    }
+
+    let mut body = String::new();
+    for (name, sections) in changes {
+        if !sections.is_empty() {
+            body.push_str(&format!("<h2>{}</h2>\n{sections}", escape(name)));
+        }
+    }
+    if body.is_empty() {
+        body.push_str("<p>There are no changes.</p>\n");
+    }
+
+    let title = format!(
+        "Documentation changes from {} to {}",
+        escape(before_name),
+        escape(after_name)
+    );
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>\n{STYLE}\n</style>\n</head>\n<body>\n<h1>{title}</h1>\n{body}</body>\n</html>\n"
+    )
+}
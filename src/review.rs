@@ -0,0 +1,159 @@
+use std::path::Path;
+
+use chrono::{DateTime, Duration, NaiveDate};
+use gherkin::{Feature, GherkinEnv};
+use serde::Serialize;
+
+use super::config::Config;
+use super::files::Source;
+use super::git::git;
+use super::specification::{extract_identifier, Documents, Error};
+
+/// When a document or requirement is due for its periodic review
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReviewStatus {
+    /// the file name of the document or the identifier of the requirement
+    pub name: String,
+    pub due: NaiveDate,
+    /// days until the review is due; negative when overdue
+    pub days: i64,
+}
+
+impl ReviewStatus {
+    pub fn is_overdue(&self) -> bool {
+        self.days < 0
+    }
+}
+
+/// The date of the last commit modifying `file` (relative to `project`), if any
+fn last_modified(project: &Path, source: &Source, file: &str) -> Result<Option<NaiveDate>, String> {
+    let mut args = vec!["log", "-1", "--format=%cI"];
+    args.extend(source.revision());
+    args.extend(["--", file]);
+    let output = git(project, &args)?;
+    let output = output.trim();
+    if output.is_empty() {
+        return Ok(None);
+    }
+    DateTime::parse_from_rfc3339(output)
+        .map(|date| Some(date.date_naive()))
+        .map_err(|_| format!("git log of {file} has an invalid date"))
+}
+
+/// Returns when each document and requirement is due for its periodic review, sorted by due
+/// date, as of `today`. The due date is the `next_review` of the document's front matter or,
+/// if `review_interval_days` is configured, the last commit modifying it plus that interval.
+pub fn get_review_status(
+    project: &Path,
+    source: &Source,
+    documents: &Documents,
+    config: &Config,
+    today: NaiveDate,
+) -> Result<Vec<ReviewStatus>, Error> {
+    let mut errors = vec![];
+    let mut due = vec![];
+
+    // the last commit modifying `file` plus the configured interval, if any
+    let from_git = |file: &str| match config.review_interval_days {
+        Some(interval) => last_modified(project, source, file)
+            .map(|date| date.map(|date| date + Duration::days(interval.into()))),
+        None => Ok(None),
+    };
+
+    for (type_, document) in documents.documents() {
+        if document.is_empty() {
+            continue;
+        }
+        let name = type_.file_name().to_string();
+        match document
+            .metadata()
+            .and_then(|metadata| metadata.next_review)
+        {
+            Some(date) => due.push((name, date)),
+            None => match from_git(type_.file_name()) {
+                Ok(date) => due.extend(date.map(|date| (name, date))),
+                Err(error) => errors.push(error),
+            },
+        }
+    }
+
+    if config.review_interval_days.is_some() {
+        match source.list_directory(project.join("features")) {
+            Ok(paths) => {
+                for path in paths
+                    .into_iter()
+                    .filter(|path| path.extension().unwrap_or_default() == "feature")
+                {
+                    let Ok(content) = source.read_file(&path) else {
+                        continue;
+                    };
+                    let Ok(feature) = Feature::parse(&content, GherkinEnv::default()) else {
+                        continue;
+                    };
+                    let Some(id) = extract_identifier(&feature.name) else {
+                        continue;
+                    };
+                    if !documents.requirements().contains_key(id) {
+                        continue;
+                    }
+                    let file = format!("features/{}", path.file_name().unwrap().to_string_lossy());
+                    match from_git(&file) {
+                        Ok(date) => due.extend(date.map(|date| (id.to_string(), date))),
+                        Err(error) => errors.push(error),
+                    }
+                }
+            }
+            Err(error) => errors.push(error),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(Error(errors));
+    }
+    let mut statuses = due
+        .into_iter()
+        .map(|(name, due)| ReviewStatus {
+            name,
+            due,
+            days: (due - today).num_days(),
+        })
+        .collect::<Vec<_>>();
+    statuses.sort_by(|a, b| a.due.cmp(&b.due).then_with(|| a.name.cmp(&b.name)));
+    Ok(statuses)
+}
+
+/// Checks that no document or requirement is overdue for its periodic review, as required for a release
+pub fn check_review_status(statuses: &[ReviewStatus]) -> Vec<String> {
+    statuses
+        .iter()
+        .filter(|status| status.is_overdue())
+        .map(|status| {
+            format!(
+                "Releases cannot contain documents or requirements overdue for periodic review, but {} was due on {}",
+                status.name, status.due
+            )
+        })
+        .collect()
+}
+
+/// Renders the documents and requirements of `statuses` in markdown
+pub fn review_status_to_markdown(statuses: &[ReviewStatus], within: u32) -> String {
+    let mut result = "# Periodic review status\n\n".to_string();
+    if statuses.is_empty() {
+        result.push_str(&format!(
+            "No document or requirement is due for review within {within} days.\n"
+        ));
+        return result;
+    }
+    result.push_str("| Document or requirement | Next review | Status |\n");
+    result.push_str("|-------------------------|-------------|--------|\n");
+    for status in statuses {
+        let state = if status.is_overdue() {
+            format!("overdue by {} days", -status.days)
+        } else {
+            format!("due in {} days", status.days)
+        };
+        result.push_str(&format!("| {} | {} | {state} |\n", status.name, status.due));
+    }
+    result
+}
@@ -0,0 +1,196 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use chrono::NaiveDate;
+use indexmap::IndexMap;
+use serde::Serialize;
+
+use super::files::Source;
+use super::git::git;
+use super::markdown::{cell, find_table};
+use super::specification::{Documents, Error, REVISION_HISTORY};
+
+/// A revision of a document: a git tag changing it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Revision {
+    /// the name of the tag
+    pub version: String,
+    pub date: NaiveDate,
+    /// the message of the tag, or the subject of its commit
+    pub description: String,
+}
+
+/// The revisions of each document, by file name
+pub type RevisionHistories = IndexMap<String, Vec<Revision>>;
+
+/// The tags (name, date and message) merged into the revision of `source` (or `HEAD`), oldest first
+fn tags(project: &Path, source: &Source) -> Result<Vec<(String, NaiveDate, String)>, String> {
+    let merged = format!("--merged={}", source.revision().unwrap_or("HEAD"));
+    let output = git(
+        project,
+        &[
+            "for-each-ref",
+            &merged,
+            "--sort=creatordate",
+            "--format=%(refname:short)%09%(creatordate:short)%09%(contents:subject)",
+            "refs/tags",
+        ],
+    )?;
+    output
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let mut fields = line.splitn(3, '\t');
+            let name = fields.next().unwrap_or_default().to_string();
+            let date = fields
+                .next()
+                .and_then(|date| date.parse::<NaiveDate>().ok())
+                .ok_or_else(|| format!("git for-each-ref of tag {name} has an invalid date"))?;
+            let description = fields.next().unwrap_or_default().to_string();
+            Ok((name, date, description))
+        })
+        .collect()
+}
+
+/// The git object of `file` (relative to `project`) at `tag`, if it exists
+fn blob(project: &Path, tag: &str, file: &str) -> Result<Option<String>, String> {
+    // the output is `<mode> <type> <object>\t<file>`, or empty when the file does not exist
+    let output = git(project, &["ls-tree", tag, "--", file])?;
+    Ok(output.split_whitespace().nth(2).map(|x| x.to_string()))
+}
+
+/// Returns the revision history of every document from the git tags of its repository: every tag
+/// (merged into the revision of `source`) where the document differs from the previous tag
+pub fn get_revision_histories(
+    project: &Path,
+    source: &Source,
+    documents: &Documents,
+) -> Result<RevisionHistories, Error> {
+    let tags = tags(project, source).map_err(|error| Error(vec![error]))?;
+
+    let mut errors = vec![];
+    let mut histories = RevisionHistories::new();
+    for (type_, document) in documents.documents() {
+        if document.is_empty() {
+            continue;
+        }
+        let file = type_.file_name();
+        let mut previous = None;
+        let mut revisions = vec![];
+        for (tag, date, description) in &tags {
+            match blob(project, tag, file) {
+                Ok(current) => {
+                    if current.is_some() && current != previous {
+                        revisions.push(Revision {
+                            version: tag.clone(),
+                            date: *date,
+                            description: description.clone(),
+                        });
+                    }
+                    previous = current;
+                }
+                Err(error) => errors.push(error),
+            }
+        }
+        histories.insert(file.to_string(), revisions);
+    }
+    if errors.is_empty() {
+        Ok(histories)
+    } else {
+        Err(Error(errors))
+    }
+}
+
+/// Checks that the revision history table of every document that has one lists exactly the
+/// revisions in `histories`, with the same dates
+pub fn check_revision_histories(
+    documents: &Documents,
+    histories: &RevisionHistories,
+) -> Vec<String> {
+    let mut errors = vec![];
+    for (type_, document) in documents.documents() {
+        let file = type_.file_name();
+        let Some(section) = document.section(REVISION_HISTORY) else {
+            continue;
+        };
+        let Some((rows, [version, date])) = find_table(section, ["version", "date"]) else {
+            errors.push(format!(
+                "The revision history of \"{file}\" must be a table with a Version and a Date column, but it is not"
+            ));
+            continue;
+        };
+        let table = rows
+            .iter()
+            .skip(1)
+            .map(|row| {
+                let column = |index: usize| row.get(index).map(|x| x.trim()).unwrap_or_default();
+                (column(version), column(date))
+            })
+            .collect::<Vec<_>>();
+
+        let revisions = histories
+            .get(file)
+            .map(|x| x.as_slice())
+            .unwrap_or_default();
+        for revision in revisions {
+            match table.iter().find(|(version, _)| *version == revision.version) {
+                None => errors.push(format!(
+                    "The revision history of \"{file}\" must match its git tags, but it does not list {}",
+                    revision.version
+                )),
+                Some((_, date)) if *date != revision.date.to_string() => errors.push(format!(
+                    "The revision history of \"{file}\" must match its git tags, but the date of {} is {date} instead of {}",
+                    revision.version, revision.date
+                )),
+                _ => {}
+            }
+        }
+        for (version, _) in &table {
+            if !revisions
+                .iter()
+                .any(|revision| revision.version == *version)
+            {
+                errors.push(format!(
+                    "The revision history of \"{file}\" must match its git tags, but {version} is not a tag changing it"
+                ));
+            }
+        }
+    }
+    errors
+}
+
+/// Renders the revision history of every document without its own revision history table in markdown
+pub fn revision_histories_to_markdown(
+    documents: &Documents,
+    histories: &RevisionHistories,
+) -> String {
+    let mut result = String::new();
+    for (type_, document) in documents.documents() {
+        let file = type_.file_name();
+        let Some(revisions) = histories.get(file) else {
+            continue;
+        };
+        if document.section(REVISION_HISTORY).is_some() {
+            continue;
+        }
+        writeln!(result, "### {file}\n").unwrap();
+        if revisions.is_empty() {
+            writeln!(result, "There are no tags changing this document.\n").unwrap();
+            continue;
+        }
+        writeln!(result, "| Version | Date | Description |").unwrap();
+        writeln!(result, "|---------|------|-------------|").unwrap();
+        for revision in revisions {
+            writeln!(
+                result,
+                "| {} | {} | {} |",
+                revision.version,
+                revision.date,
+                cell(&revision.description)
+            )
+            .unwrap();
+        }
+        writeln!(result).unwrap();
+    }
+    result
+}
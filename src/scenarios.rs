@@ -0,0 +1,126 @@
+use gherkin::{Feature, Scenario, StepType};
+use indexmap::IndexSet;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use super::specification::{scenarios, Documents};
+
+/// The placeholders (`<name>`) of the steps of `scenario`, including their docstrings and tables
+fn placeholders(scenario: &Scenario) -> IndexSet<String> {
+    lazy_static! {
+        static ref PLACEHOLDER: Regex = Regex::new(r"<(?P<name>[^<>\n]+)>").unwrap();
+    }
+    scenario
+        .steps
+        .iter()
+        .flat_map(|step| {
+            std::iter::once(step.value.as_str())
+                .chain(step.docstring.as_deref())
+                .chain(
+                    step.table
+                        .iter()
+                        .flat_map(|table| table.rows.iter().flatten().map(|x| x.as_str())),
+                )
+        })
+        .flat_map(|text| PLACEHOLDER.captures_iter(text))
+        .map(|captures| captures["name"].to_string())
+        .collect()
+}
+
+/// Checks the structure of the scenarios of the requirement `id`: each has a `Then` step, its
+/// steps do not go back to `Given` after a `When` or `Then`, names are unique, and outlines have
+/// examples binding every placeholder
+fn check_structure(id: &str, feature: &Feature) -> Vec<String> {
+    let mut issues = vec![];
+    let mut names = IndexSet::new();
+    for scenario in scenarios(feature) {
+        let name = &scenario.name;
+        if !names.insert(name) {
+            issues.push(format!(
+                "Scenario names must be unique within a requirement, but \"{name}\" of {id} is not"
+            ));
+        }
+
+        if !scenario.steps.iter().any(|step| step.ty == StepType::Then) {
+            issues.push(format!(
+                "Scenarios must have at least one Then step, but \"{name}\" of {id} does not"
+            ));
+        }
+        let back_to_given = scenario
+            .steps
+            .windows(2)
+            .any(|steps| steps[0].ty != StepType::Given && steps[1].ty == StepType::Given);
+        if back_to_given {
+            issues.push(format!(
+                "Steps of scenarios must follow Given, When and Then, but \"{name}\" of {id} has a Given after a When or Then"
+            ));
+        }
+
+        let is_outline = ["Scenario Outline", "Scenario Template"]
+            .contains(&scenario.keyword.trim())
+            || !scenario.examples.is_empty();
+        if !is_outline {
+            continue;
+        }
+        let tables = scenario
+            .examples
+            .iter()
+            .filter_map(|examples| examples.table.as_ref())
+            .collect::<Vec<_>>();
+        if tables.is_empty() || tables.iter().any(|table| table.rows.len() < 2) {
+            issues.push(format!(
+                "Scenario outlines must have examples with at least one row, but \"{name}\" of {id} does not"
+            ));
+            continue;
+        }
+        for placeholder in placeholders(scenario) {
+            if !tables
+                .iter()
+                .all(|table| table.rows[0].contains(&placeholder))
+            {
+                issues.push(format!(
+                    "Placeholders of scenario outlines must be bound by their examples, but <{placeholder}> of \"{name}\" of {id} is not"
+                ));
+            }
+        }
+    }
+    issues
+}
+
+/// Requirements without scenarios, which can't be verified
+fn without_scenarios(documents: &Documents) -> Vec<String> {
+    documents
+        .features()
+        .filter(|(_, feature)| scenarios(feature).next().is_none())
+        .map(|(id, _)| id.clone())
+        .collect()
+}
+
+/// The issues of the structure of the scenarios of every requirement
+fn structure_issues(documents: &Documents) -> Vec<String> {
+    documents
+        .features()
+        .flat_map(|(id, feature)| check_structure(id, &feature))
+        .collect()
+}
+
+/// Checks that every requirement has at least one scenario and that their structure is valid, as
+/// required for a release
+pub fn check_release_scenarios(documents: &Documents) -> Vec<String> {
+    without_scenarios(documents)
+        .into_iter()
+        .map(|id| {
+            format!("Releases cannot contain requirements without scenarios, but {id} has none")
+        })
+        .chain(structure_issues(documents))
+        .collect()
+}
+
+/// Warnings of requirements without scenarios and of the structure of scenarios
+pub fn get_scenario_warnings(documents: &Documents) -> Vec<String> {
+    without_scenarios(documents)
+        .into_iter()
+        .map(|id| format!("{id} has no scenario or example"))
+        .chain(structure_issues(documents))
+        .collect()
+}
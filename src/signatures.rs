@@ -0,0 +1,206 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::SystemTime;
+
+use chrono::{DateTime, SubsecRound, Utc};
+use ed25519_dalek::{Signature as Ed25519Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use indexmap::IndexMap;
+use rand_core::OsRng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::files::{self, Source};
+
+/// Name of the file, in the documentation's directory, with the trusted public key of each signer
+pub const PUBLIC_KEYS_FILE: &str = "public_keys.json";
+
+/// Meaning of an electronic signature
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Meaning {
+    Authored,
+    Reviewed,
+    Approved,
+}
+
+impl FromStr for Meaning {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "authored" => Ok(Self::Authored),
+            "reviewed" => Ok(Self::Reviewed),
+            "approved" => Ok(Self::Approved),
+            _ => Err(format!(
+                "meaning must be one of authored, reviewed or approved, but \"{s}\" is not"
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for Meaning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Authored => write!(f, "authored"),
+            Self::Reviewed => write!(f, "reviewed"),
+            Self::Approved => write!(f, "approved"),
+        }
+    }
+}
+
+/// An electronic signature of a baseline
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Signature {
+    pub signer: String,
+    pub meaning: Meaning,
+    pub timestamp: DateTime<Utc>,
+    /// Ed25519 public key of the signer, in hexadecimal
+    pub public_key: String,
+    /// Ed25519 signature, in hexadecimal
+    pub signature: String,
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|x| format!("{x:02x}")).collect()
+}
+
+fn from_hex<const N: usize>(value: &str) -> Option<[u8; N]> {
+    let value = value.trim();
+    if value.len() != 2 * N || !value.is_ascii() {
+        return None;
+    }
+    let mut bytes = [0; N];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&value[2 * i..2 * i + 2], 16).ok()?;
+    }
+    Some(bytes)
+}
+
+/// The message signed: the SHA-256 of the baseline with the signer, meaning and time of the signature
+fn message(baseline: &str, signer: &str, meaning: Meaning, timestamp: &DateTime<Utc>) -> String {
+    let digest = to_hex(&Sha256::digest(baseline.as_bytes()));
+    format!("{digest}\n{signer}\n{meaning}\n{}", timestamp.to_rfc3339())
+}
+
+/// Returns a new Ed25519 private key and its public key, both in hexadecimal
+pub fn generate_key() -> (String, String) {
+    let key = SigningKey::generate(&mut OsRng);
+    (
+        to_hex(key.as_bytes()),
+        to_hex(key.verifying_key().as_bytes()),
+    )
+}
+
+/// Signs the content of the baseline `baseline` as `signer` with the meaning `meaning`, using
+/// the private key `key` (in hexadecimal)
+pub fn sign(
+    baseline: &str,
+    key: &str,
+    signer: &str,
+    meaning: Meaning,
+) -> Result<Signature, String> {
+    let key = from_hex::<32>(key)
+        .map(|key| SigningKey::from_bytes(&key))
+        .ok_or_else(|| "Private keys must be 32 bytes in hexadecimal, but it is not".to_string())?;
+    let timestamp = DateTime::<Utc>::from(SystemTime::now()).trunc_subsecs(0);
+    let signature = key.sign(message(baseline, signer, meaning, &timestamp).as_bytes());
+    Ok(Signature {
+        signer: signer.to_string(),
+        meaning,
+        timestamp,
+        public_key: to_hex(key.verifying_key().as_bytes()),
+        signature: to_hex(&signature.to_bytes()),
+    })
+}
+
+/// The file with the signatures of the baseline `baseline`, e.g. `baseline.signatures.json`
+/// for `baseline.json`
+pub fn signatures_path(baseline: &Path) -> PathBuf {
+    baseline.with_extension("signatures.json")
+}
+
+/// Returns the signatures in the file `path`, or none if it does not exist
+pub fn get_signatures(path: &Path) -> Result<Vec<Signature>, String> {
+    if !path.is_file() {
+        return Ok(vec![]);
+    }
+    let content = files::read_file(path)?;
+    serde_json::from_str(&content).map_err(|error| {
+        format!(
+            "{} must be a valid list of signatures, but it is not: {error}",
+            path.to_string_lossy()
+        )
+    })
+}
+
+/// Returns the trusted public key (in hexadecimal) of each signer of the documentation in `project`
+pub fn get_public_keys(
+    project: &Path,
+    source: &Source,
+) -> Result<IndexMap<String, String>, String> {
+    let path = project.join(PUBLIC_KEYS_FILE);
+    if !source.is_file(&path) {
+        return Ok(IndexMap::new());
+    }
+    let content = source.read_file(path)?;
+    serde_json::from_str(&content).map_err(|error| {
+        format!("{PUBLIC_KEYS_FILE} must map signers to public keys, but it does not: {error}")
+    })
+}
+
+/// Whether `signature` is a valid signature of the content `baseline` with `public_key`
+fn is_valid(baseline: &str, signature: &Signature, public_key: &str) -> bool {
+    let Some(key) = from_hex::<32>(public_key).and_then(|key| VerifyingKey::from_bytes(&key).ok())
+    else {
+        return false;
+    };
+    let Some(bytes) = from_hex::<64>(&signature.signature) else {
+        return false;
+    };
+    let message = message(
+        baseline,
+        &signature.signer,
+        signature.meaning,
+        &signature.timestamp,
+    );
+    key.verify(message.as_bytes(), &Ed25519Signature::from_bytes(&bytes))
+        .is_ok()
+}
+
+/// Checks that every signature of the content `baseline` is valid and made with the trusted key of
+/// its signer, and that the baseline is signed with every meaning of `required`
+pub fn verify_signatures(
+    baseline: &str,
+    signatures: &[Signature],
+    public_keys: &IndexMap<String, String>,
+    required: &[Meaning],
+) -> Vec<String> {
+    let mut errors = vec![];
+    let mut signed = vec![];
+    for signature in signatures {
+        let name = format!("{} ({})", signature.signer, signature.meaning);
+        match public_keys.get(&signature.signer) {
+            Some(key) if key.trim() == signature.public_key => {
+                if is_valid(baseline, signature, key) {
+                    signed.push(signature.meaning);
+                } else {
+                    errors.push(format!(
+                        "Signatures must be valid for the baseline, but the signature of {name} is not"
+                    ));
+                }
+            }
+            _ => errors.push(format!(
+                "Signatures must be made with a trusted key in {PUBLIC_KEYS_FILE}, but the signature of {name} is not"
+            )),
+        }
+    }
+    for meaning in required {
+        if !signed.contains(meaning) {
+            errors.push(format!(
+                "Baselines must be signed as {meaning}, but this baseline is not"
+            ));
+        }
+    }
+    errors
+}
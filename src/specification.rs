@@ -1,19 +1,31 @@
+use std::ops::RangeInclusive;
 use std::path::PathBuf;
 
-use gherkin::{Feature, GherkinEnv};
+use gherkin::{Feature, GherkinEnv, Scenario};
 use indexmap::{IndexMap, IndexSet};
 use lazy_static::lazy_static;
 use pulldown_cmark::{Event, HeadingLevel, Parser, Tag};
 use regex::Regex;
 use serde::Serialize;
 
-use super::files;
+use super::deviations::check_deviations;
+use super::files::Source;
+use super::glossary::check_glossary;
+use super::lifecycle::{is_requirement_in_progress, parse_statuses};
+use super::metadata::{split_front_matter, DocumentStatus, Metadata};
+use super::records::{check_test_records, TestRecords};
 
 /// Sequence of errors
 pub struct Error(pub Vec<String>);
 
+impl From<String> for Error {
+    fn from(error: String) -> Self {
+        Self(vec![error])
+    }
+}
+
 /// Every specification document.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum DocumentType {
     //Requirements,
     Design,
@@ -22,9 +34,13 @@ pub enum DocumentType {
     UserManual,
     OperatorManual,
     RetirementPlan,
+    Deviations,
+    Glossary,
 }
 
-enum SpecificationType {
+/// Every type of item in the documentation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpecificationType {
     Requirements,
     Document(DocumentType),
 }
@@ -39,6 +55,8 @@ impl DocumentType {
             DocumentType::UserManual => "user_manual.md",
             DocumentType::OperatorManual => "operator_manual.md",
             DocumentType::RetirementPlan => "retirement_plan.md",
+            DocumentType::Deviations => "deviations.md",
+            DocumentType::Glossary => "glossary.md",
         }
     }
 }
@@ -50,14 +68,188 @@ pub type Requirements = IndexMap<String, String>;
 pub struct Document {
     text: String, // markdown
     trace: Trace, // parsed trace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<Metadata>, // parsed front matter
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    statuses: IndexMap<String, DocumentStatus>, // status of each item, if declared
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    sections: IndexMap<String, String>, // markdown of each section without identifier, by heading
+    #[serde(skip)]
+    body_start: usize, // offset of the text after the front matter
 }
 
 impl Document {
-    pub fn try_new(text: String, type_: DocumentType) -> Result<Self, Error> {
-        get_trace(&text, type_).map(|trace| Self { text, trace })
+    /// Parses the document of type `type_` from its markdown `text`, where sections (`##`) without
+    /// identifier are allowed with a heading in `sections` or [`REVISION_HISTORY`]
+    pub fn try_new(text: String, type_: DocumentType, sections: &[String]) -> Result<Self, Error> {
+        let (metadata, body_start) = split_front_matter(&text, type_.file_name()).map_err(Error)?;
+        let trace = get_trace(&text[body_start..], type_, sections)?;
+        let mut document = Self {
+            text,
+            trace,
+            metadata,
+            statuses: Default::default(),
+            sections: Default::default(),
+            body_start,
+        };
+        document.sections = document
+            .section_spans()
+            .into_iter()
+            .filter(|(heading, _)| extract_identifier(heading).is_none())
+            .map(|(heading, span)| {
+                let text = document.lines(span);
+                (heading, text)
+            })
+            .collect();
+        // the deviation log has its own statuses, their resolution
+        if type_ != DocumentType::Deviations {
+            document.statuses = parse_statuses(&document.items()).map_err(Error)?;
+        }
+        Ok(document)
+    }
+
+    /// The markdown of this document, including its front matter
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The markdown of this document after its front matter
+    pub fn body(&self) -> &str {
+        &self.text[self.body_start..]
+    }
+
+    /// The metadata declared in the front matter of this document, if any
+    pub fn metadata(&self) -> Option<&Metadata> {
+        self.metadata.as_ref()
+    }
+
+    /// The status (`Status: <status>`) of each item of this document that declares one
+    pub fn statuses(&self) -> &IndexMap<String, DocumentStatus> {
+        &self.statuses
+    }
+
+    /// Whether the item `id` is in progress (draft or in review), itself or because this document is
+    pub fn is_in_progress(&self, id: &str) -> bool {
+        self.metadata()
+            .and_then(|metadata| metadata.status)
+            .iter()
+            .chain(self.statuses.get(id))
+            .any(|status| status.is_in_progress())
+    }
+
+    /// The markdown of each section (`##`) of this document without identifier, e.g. its purpose
+    /// or revision history, by heading
+    pub fn sections(&self) -> &IndexMap<String, String> {
+        &self.sections
+    }
+
+    /// The markdown of the section (`##`) of this document without identifier with heading `heading`, if any
+    pub fn section(&self, heading: &str) -> Option<&str> {
+        self.sections.get(heading).map(|x| x.as_str())
+    }
+
+    /// The trace of each item of this document
+    pub fn trace(&self) -> &Trace {
+        &self.trace
+    }
+
+    /// Whether this document is empty, e.g. an optional document that does not exist
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// The title of each item of this document, e.g. `Audit trail` of `## DS-1 - Audit trail`
+    pub fn titles(&self) -> IndexMap<String, String> {
+        let mut titles = IndexMap::new();
+        let mut heading = None::<String>;
+        Parser::new(self.body()).for_each(|event| match event {
+            Event::Start(Tag::Heading(HeadingLevel::H2, _, _)) => heading = Some(String::new()),
+            Event::Text(inner) | Event::Code(inner) if heading.is_some() => {
+                heading.as_mut().unwrap().push_str(&inner)
+            }
+            Event::End(Tag::Heading(HeadingLevel::H2, _, _)) => {
+                let heading = heading.take().unwrap_or_default();
+                if let Some((id, title)) = heading.split_once(" - ") {
+                    titles.insert(id.to_string(), title.to_string());
+                }
+            }
+            _ => {}
+        });
+        titles
+    }
+
+    /// The heading and lines (1-based, inclusive) of each section (`##`) of this document, from its
+    /// heading until the next section
+    fn section_spans(&self) -> Vec<(String, RangeInclusive<usize>)> {
+        let line = |offset: usize| self.text[..offset].matches('\n').count() + 1;
+        let last_line = line(self.text.len());
+
+        let mut starts = vec![];
+        let mut heading = None::<(usize, String)>;
+        Parser::new(self.body())
+            .into_offset_iter()
+            .for_each(|(event, range)| match event {
+                Event::Start(Tag::Heading(HeadingLevel::H2, _, _)) => {
+                    heading = Some((self.body_start + range.start, String::new()))
+                }
+                Event::Text(inner) | Event::Code(inner) if heading.is_some() => {
+                    heading.as_mut().unwrap().1.push_str(&inner)
+                }
+                Event::End(Tag::Heading(HeadingLevel::H2, _, _)) => {
+                    if let Some((start, heading)) = heading.take() {
+                        starts.push((heading, line(start)));
+                    }
+                }
+                _ => {}
+            });
+
+        let ends = starts
+            .iter()
+            .skip(1)
+            .map(|(_, start)| start - 1)
+            .chain(std::iter::once(last_line));
+        starts
+            .iter()
+            .zip(ends)
+            .map(|((heading, start), end)| (heading.clone(), *start..=end))
+            .collect()
+    }
+
+    /// The lines (1-based, inclusive) of each item of this document, from its heading until the next section
+    pub fn spans(&self) -> IndexMap<String, RangeInclusive<usize>> {
+        self.section_spans()
+            .into_iter()
+            .filter_map(|(heading, span)| {
+                extract_identifier(&heading).map(|id| (id.to_string(), span))
+            })
+            .collect()
+    }
+
+    /// The markdown of the lines `span` (1-based, inclusive) of this document
+    fn lines(&self, span: RangeInclusive<usize>) -> String {
+        let lines = self.text.lines().collect::<Vec<_>>();
+        let start = (*span.start() - 1).min(lines.len());
+        let end = (*span.end()).min(lines.len());
+        lines[start..end].join("\n")
+    }
+
+    /// The markdown of each item of this document, from its heading until the next section
+    pub fn items(&self) -> IndexMap<String, String> {
+        self.spans()
+            .into_iter()
+            .map(|(id, span)| (id, self.lines(span)))
+            .collect()
     }
 }
 
+/// The scenarios of `feature`, including those of its rules
+pub fn scenarios(feature: &Feature) -> impl Iterator<Item = &Scenario> {
+    feature
+        .scenarios
+        .iter()
+        .chain(feature.rules.iter().flat_map(|rule| rule.scenarios.iter()))
+}
+
 #[derive(Debug, Serialize)]
 pub struct Documents {
     requirements: Requirements,
@@ -67,6 +259,12 @@ pub struct Documents {
     user_manual: Document,
     operator_manual: Document,
     retirement_plan: Document,
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    test_records: TestRecords,
+    #[serde(skip_serializing_if = "Document::is_empty")]
+    deviations: Document,
+    #[serde(skip_serializing_if = "Document::is_empty")]
+    glossary: Document,
 }
 
 impl Documents {
@@ -96,11 +294,100 @@ impl Documents {
             user_manual,
             operator_manual,
             retirement_plan,
+            test_records: Default::default(),
+            deviations: Default::default(),
+            glossary: Default::default(),
         })
     }
+
+    /// Adds executed test records to the documentation, checking that they are linked
+    /// to existing scenarios and tests.
+    pub fn with_test_records(mut self, test_records: TestRecords) -> Result<Self, Error> {
+        let errors = check_test_records(&self, &test_records);
+        if errors.is_empty() {
+            self.test_records = test_records;
+            Ok(self)
+        } else {
+            Err(Error(errors))
+        }
+    }
+
+    /// Adds the deviation log to the documentation, checking that deviations are traced to
+    /// existing requirements, tests or test records and that every failed test record is traced by a deviation.
+    pub fn with_deviations(mut self, deviations: Document) -> Result<Self, Error> {
+        let errors = check_deviations(&self, &deviations);
+        if errors.is_empty() {
+            self.deviations = deviations;
+            Ok(self)
+        } else {
+            Err(Error(errors))
+        }
+    }
+
+    /// Adds the glossary to the documentation, checking that its terms are not traced and that every
+    /// abbreviation used in the documentation is defined in it.
+    pub fn with_glossary(mut self, glossary: Document) -> Result<Self, Error> {
+        let errors = check_glossary(&self, &glossary);
+        if errors.is_empty() {
+            self.glossary = glossary;
+            Ok(self)
+        } else {
+            Err(Error(errors))
+        }
+    }
+
+    /// The executed test records, by identifier
+    pub fn test_records(&self) -> &TestRecords {
+        &self.test_records
+    }
+
+    /// The requirements, by identifier
+    pub fn requirements(&self) -> &Requirements {
+        &self.requirements
+    }
+
+    /// The parsed Gherkin feature of every requirement
+    pub fn features(&self) -> impl Iterator<Item = (&String, Feature)> {
+        self.requirements.iter().map(|(id, content)| {
+            let feature = Feature::parse(content, GherkinEnv::default())
+                .expect("requirements to be valid Gherkin");
+            (id, feature)
+        })
+    }
+
+    /// The document of type `type_`
+    pub fn document(&self, type_: DocumentType) -> &Document {
+        match type_ {
+            DocumentType::Design => &self.design_specification,
+            DocumentType::Risks => &self.risk_assessment,
+            DocumentType::Tests => &self.verification_plan,
+            DocumentType::UserManual => &self.user_manual,
+            DocumentType::OperatorManual => &self.operator_manual,
+            DocumentType::RetirementPlan => &self.retirement_plan,
+            DocumentType::Deviations => &self.deviations,
+            DocumentType::Glossary => &self.glossary,
+        }
+    }
+
+    /// Every document other than the requirements
+    pub fn documents(&self) -> [(DocumentType, &Document); 8] {
+        [
+            (DocumentType::Design, &self.design_specification),
+            (DocumentType::Risks, &self.risk_assessment),
+            (DocumentType::Tests, &self.verification_plan),
+            (DocumentType::UserManual, &self.user_manual),
+            (DocumentType::OperatorManual, &self.operator_manual),
+            (DocumentType::RetirementPlan, &self.retirement_plan),
+            (DocumentType::Deviations, &self.deviations),
+            (DocumentType::Glossary, &self.glossary),
+        ]
+    }
 }
 
-fn extract_identifier(input: &str) -> Option<&str> {
+/// Heading of the section of a document with its revision history, which has no identifier
+pub const REVISION_HISTORY: &str = "Revision history";
+
+pub(crate) fn extract_identifier(input: &str) -> Option<&str> {
     lazy_static! {
         static ref RE: Regex = Regex::new(r"^(?P<id>.*?) - .*$").unwrap();
     }
@@ -118,7 +405,7 @@ enum TraceState {
     Item,
 }
 
-fn parse(markdown_input: &str, type_: DocumentType) -> (Trace, Vec<String>) {
+fn parse(markdown_input: &str, type_: DocumentType, sections: &[String]) -> (Trace, Vec<String>) {
     let expected_title = match type_ {
         DocumentType::Design => "Design specification",
         DocumentType::Tests => "Verification plan",
@@ -126,11 +413,15 @@ fn parse(markdown_input: &str, type_: DocumentType) -> (Trace, Vec<String>) {
         DocumentType::UserManual => "User manual",
         DocumentType::OperatorManual => "Operator manual",
         DocumentType::RetirementPlan => "Retirement plan",
+        DocumentType::Deviations => "Deviations",
+        DocumentType::Glossary => "Glossary",
     };
 
     let parser = Parser::new(markdown_input);
 
     let mut in_heading = false;
+    // whether the current section is an allowed section without identifier, e.g. the revision history
+    let mut in_free_section = false;
     let mut in_title = false;
     let mut has_title = false;
     let mut trace_state = TraceState::None;
@@ -147,19 +438,26 @@ fn parse(markdown_input: &str, type_: DocumentType) -> (Trace, Vec<String>) {
             }
             has_title = true;
         }
-        Event::Text(inner) if in_title => {
-            if inner.as_bytes() != expected_title.as_bytes() {
-                errors.push(format!(
-                    "\"{}\" must start with \"# {expected_title}\" but starts with \"# {inner}\"",
-                    type_.file_name(),
-                ))
-            }
+        Event::Text(inner) if in_title && inner.as_bytes() != expected_title.as_bytes() => {
+            errors.push(format!(
+                "\"{}\" must start with \"# {expected_title}\" but starts with \"# {inner}\"",
+                type_.file_name(),
+            ))
         }
         Event::End(Tag::Heading(HeadingLevel::H1, _, _)) => {
             in_title = false;
         }
         Event::Start(Tag::Heading(HeadingLevel::H2, _, _)) => {
             in_heading = true;
+            in_free_section = false;
+            trace_state = TraceState::None;
+        }
+        Event::Text(inner)
+            if in_heading
+                && (inner.as_ref() == REVISION_HISTORY
+                    || sections.iter().any(|section| section == inner.as_ref())) =>
+        {
+            in_free_section = true;
         }
         Event::Text(inner) if in_heading => {
             let id = extract_identifier(inner.as_ref());
@@ -174,7 +472,9 @@ fn parse(markdown_input: &str, type_: DocumentType) -> (Trace, Vec<String>) {
         Event::End(Tag::Heading(HeadingLevel::H2, _, _)) => {
             in_heading = false;
         }
-        Event::Start(Tag::Heading(HeadingLevel::H3, _, _)) if !trace.is_empty() => {
+        Event::Start(Tag::Heading(HeadingLevel::H3, _, _))
+            if !trace.is_empty() && !in_free_section =>
+        {
             trace_state = TraceState::CheckHeading;
         }
         Event::Text(inner)
@@ -267,6 +567,18 @@ fn check_ids<'a, I: Iterator<Item = &'a String> + Clone>(
                 format!("Headings in retirement plan must start with \"RETIRE-\". \"{heading}\" does not.")
             })
             .collect(),
+        SpecificationType::Document(DocumentType::Deviations) => headings
+            .filter(|heading| !heading.starts_with("DEV-"))
+            .map(|heading| {
+                format!("Headings in deviations must start with \"DEV-\". \"{heading}\" does not.")
+            })
+            .collect(),
+        SpecificationType::Document(DocumentType::Glossary) => headings
+            .filter(|heading| !heading.starts_with("TERM-"))
+            .map(|heading| {
+                format!("Headings in glossary must start with \"TERM-\". \"{heading}\" does not.")
+            })
+            .collect(),
     };
 
     errors
@@ -288,8 +600,8 @@ fn check_trace(trace: &Trace) -> Vec<String> {
     errors
 }
 
-fn get_trace(content: &str, type_: DocumentType) -> Result<Trace, Error> {
-    let (trace, mut errors) = parse(content, type_);
+fn get_trace(content: &str, type_: DocumentType, sections: &[String]) -> Result<Trace, Error> {
+    let (trace, mut errors) = parse(content, type_, sections);
 
     errors.extend(check_ids(trace.keys(), SpecificationType::Document(type_)));
     errors.extend(check_trace(&trace));
@@ -320,8 +632,15 @@ fn check_documentation(
     let operator_manual = &operator_manual.trace;
     let retirement_plan = &retirement_plan.trace;
 
-    let mut uncovered_requirements = requirements.keys().collect::<IndexSet<_>>();
+    // requirements in progress are not part of the validated state and need not be covered yet
+    let mut uncovered_requirements = requirements
+        .iter()
+        .filter(|(_, content)| !is_requirement_in_progress(content))
+        .map(|(id, _)| id)
+        .collect::<IndexSet<_>>();
     for (test, values) in tests {
+        // likewise, tests in progress do not cover requirements yet
+        let covers = !verification_plan.is_in_progress(test);
         for value in values {
             let is_valid = risks.contains_key(value) || requirements.contains_key(value);
             if !is_valid {
@@ -332,7 +651,9 @@ fn check_documentation(
                     errors.push(format!("Tests can only be traced to existing risks or requirements, but {test} is traced to something else"));
                 }
             }
-            uncovered_requirements.remove(value);
+            if covers {
+                uncovered_requirements.remove(value);
+            }
         }
     }
     if !uncovered_requirements.is_empty() {
@@ -410,10 +731,14 @@ fn check_documentation(
     }
 }
 
-pub fn get_specification(project: PathBuf, errors: &mut Vec<String>) -> IndexMap<String, String> {
+pub fn get_specification(
+    project: PathBuf,
+    source: &Source,
+    errors: &mut Vec<String>,
+) -> IndexMap<String, String> {
     let path = project.join("features");
 
-    let paths = match files::list_directory(path) {
+    let paths = match source.list_directory(path) {
         Ok(paths) => paths,
         Err(error) => {
             errors.push(error);
@@ -428,7 +753,7 @@ pub fn get_specification(project: PathBuf, errors: &mut Vec<String>) -> IndexMap
         .filter(|path| path.extension().unwrap_or_default() == "feature")
         .for_each(|path| {
             // open the file
-            let content = match files::read_file(&path) {
+            let content = match source.read_file(&path) {
                 Ok(content) => content,
                 Err(error) => {
                     errors.push(error);
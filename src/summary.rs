@@ -0,0 +1,326 @@
+use std::fmt::Write;
+
+use indexmap::IndexMap;
+use pulldown_cmark::escape::escape_html;
+use pulldown_cmark::{html, Event, Options, Parser, Tag};
+use regex::Regex;
+use sha2::{Digest, Sha256};
+
+use super::deviations::{get_deviations, Resolution};
+use super::evidence::{Verification, VerificationStatus};
+use super::git::Attributions;
+use super::glossary::{tooltips, Glossary};
+use super::lifecycle::get_in_progress;
+use super::markdown::cell;
+use super::revisions::{revision_histories_to_markdown, RevisionHistories};
+use super::specification::{DocumentType, Documents};
+
+/// Short SHA-256 digest of `text`, used as the version of a document without front matter
+fn version(text: &str) -> String {
+    let digest = Sha256::digest(text.as_bytes());
+    digest.iter().take(6).map(|x| format!("{x:02x}")).collect()
+}
+
+fn status(status: VerificationStatus) -> &'static str {
+    match status {
+        VerificationStatus::Passed => "passed",
+        VerificationStatus::Failed => "failed",
+        VerificationStatus::Unverified => "unverified",
+    }
+}
+
+/// Returns the validation summary report of the documentation in markdown, with the verification
+/// status of each requirement from `verification` (evidence) and from the executed test records,
+/// the last commit modifying each item from `attributions`, and the revision history of each document
+/// without its own from `revision_histories`, if any. The conclusion and signatures are left to be completed.
+pub fn summary(
+    documents: &Documents,
+    verification: Option<&IndexMap<String, Verification>>,
+    attributions: Option<&Attributions>,
+    revision_histories: Option<&RevisionHistories>,
+) -> String {
+    let mut report = String::new();
+    let features = documents.features().collect::<IndexMap<_, _>>();
+    let tests = documents.document(DocumentType::Tests).trace();
+
+    writeln!(report, "# Validation summary report\n").unwrap();
+
+    writeln!(report, "## Intended use\n").unwrap();
+    writeln!(
+        report,
+        "The intended use of the software is specified by the following requirements:\n"
+    )
+    .unwrap();
+    writeln!(report, "| Requirement | Title |").unwrap();
+    writeln!(report, "|-------------|-------|").unwrap();
+    for (id, feature) in &features {
+        let title = feature
+            .name
+            .split_once(" - ")
+            .map(|(_, title)| title)
+            .unwrap_or_default();
+        writeln!(report, "| {id} | {} |", cell(title)).unwrap();
+    }
+    writeln!(report).unwrap();
+
+    writeln!(report, "## Documents\n").unwrap();
+    writeln!(
+        report,
+        "| Document | Items | Version | Status | Effective date |"
+    )
+    .unwrap();
+    writeln!(
+        report,
+        "|----------|-------|---------|--------|----------------|"
+    )
+    .unwrap();
+    let requirements = documents
+        .requirements()
+        .values()
+        .map(|x| x.as_str())
+        .collect::<String>();
+    writeln!(
+        report,
+        "| features | {} | {} | | |",
+        documents.requirements().len(),
+        version(&requirements)
+    )
+    .unwrap();
+    for (type_, document) in documents.documents() {
+        if document.is_empty() {
+            continue;
+        }
+        // the control information of the front matter, if any
+        let metadata = document.metadata().cloned().unwrap_or_default();
+        writeln!(
+            report,
+            "| {} | {} | {} | {} | {} |",
+            type_.file_name(),
+            document.trace().len(),
+            metadata
+                .version
+                .map(|x| cell(&x))
+                .unwrap_or_else(|| version(document.text())),
+            metadata.status.map(|x| x.to_string()).unwrap_or_default(),
+            metadata
+                .effective_date
+                .map(|x| x.to_string())
+                .unwrap_or_default(),
+        )
+        .unwrap();
+    }
+    for (id, record) in documents.test_records() {
+        writeln!(report, "| {id} | 1 | {} | | |", version(&record.text)).unwrap();
+    }
+    writeln!(report).unwrap();
+
+    if let Some(histories) = revision_histories {
+        let histories = revision_histories_to_markdown(documents, histories);
+        if !histories.is_empty() {
+            writeln!(report, "## Revision history\n").unwrap();
+            write!(report, "{histories}").unwrap();
+        }
+    }
+
+    let in_progress = get_in_progress(documents);
+    if !in_progress.is_empty() {
+        writeln!(report, "## Work in progress\n").unwrap();
+        writeln!(
+            report,
+            "The following documents and items are in progress and are not part of the validated state:\n"
+        )
+        .unwrap();
+        writeln!(report, "| Document or item | Status |").unwrap();
+        writeln!(report, "|------------------|--------|").unwrap();
+        for (name, status) in in_progress {
+            writeln!(report, "| {} | {status} |", name.name()).unwrap();
+        }
+        writeln!(report).unwrap();
+    }
+
+    if let Some(attributions) = attributions {
+        writeln!(report, "## Attribution\n").unwrap();
+        writeln!(report, "| Item | Author | Date | Commit |").unwrap();
+        writeln!(report, "|------|--------|------|--------|").unwrap();
+        for (id, attribution) in attributions {
+            writeln!(
+                report,
+                "| {id} | {} | {} | {} |",
+                cell(&attribution.author),
+                attribution.date.to_rfc3339(),
+                &attribution.commit[..8]
+            )
+            .unwrap();
+        }
+        writeln!(report).unwrap();
+    }
+
+    writeln!(report, "## Test execution\n").unwrap();
+    writeln!(report, "| Requirement | Tests | Test records | Status |").unwrap();
+    writeln!(report, "|-------------|-------|--------------|--------|").unwrap();
+    for id in documents.requirements().keys() {
+        let tests = tests
+            .iter()
+            .filter(|(_, trace)| trace.contains(id))
+            .map(|(test, _)| test.as_str())
+            .collect::<Vec<_>>();
+        let records = documents
+            .test_records()
+            .iter()
+            .filter(|(_, record)| record.requirement == *id)
+            .collect::<Vec<_>>();
+
+        let evidence = verification
+            .and_then(|verification| verification.get(id))
+            .map(|verification| verification.status)
+            .unwrap_or(VerificationStatus::Unverified);
+        let result = if evidence == VerificationStatus::Failed
+            || records.iter().any(|(_, record)| !record.passed())
+        {
+            VerificationStatus::Failed
+        } else if evidence == VerificationStatus::Passed || !records.is_empty() {
+            VerificationStatus::Passed
+        } else {
+            VerificationStatus::Unverified
+        };
+
+        let records = records
+            .iter()
+            .map(|(id, _)| id.as_str())
+            .collect::<Vec<_>>();
+        writeln!(
+            report,
+            "| {id} | {} | {} | {} |",
+            tests.join(", "),
+            records.join(", "),
+            status(result)
+        )
+        .unwrap();
+    }
+    writeln!(report).unwrap();
+
+    writeln!(report, "## Open deviations\n").unwrap();
+    let titles = documents.document(DocumentType::Deviations).titles();
+    let deviations = get_deviations(documents)
+        .into_iter()
+        .filter(|(_, deviation)| deviation.status != Resolution::Closed)
+        .collect::<Vec<_>>();
+    if deviations.is_empty() {
+        writeln!(report, "There are no open deviations.\n").unwrap();
+    } else {
+        writeln!(report, "| Deviation | Title | Severity | Status |").unwrap();
+        writeln!(report, "|-----------|-------|----------|--------|").unwrap();
+        for (id, deviation) in deviations {
+            let status = match deviation.status {
+                Resolution::Open => "open",
+                Resolution::Accepted => "accepted",
+                Resolution::Closed => "closed",
+            };
+            writeln!(
+                report,
+                "| {id} | {} | {} | {status} |",
+                cell(titles.get(&id).map(|x| x.as_str()).unwrap_or_default()),
+                deviation.severity,
+            )
+            .unwrap();
+        }
+        writeln!(report).unwrap();
+    }
+
+    writeln!(report, "## Residual risks\n").unwrap();
+    let risks = documents.document(DocumentType::Risks);
+    let titles = risks.titles();
+    writeln!(report, "| Risk | Title | Mitigated by |").unwrap();
+    writeln!(report, "|------|-------|--------------|").unwrap();
+    for id in risks.trace().keys() {
+        let mitigations = documents
+            .documents()
+            .into_iter()
+            .flat_map(|(_, document)| document.trace())
+            .filter(|(_, trace)| trace.contains(id))
+            .map(|(item, _)| item.as_str())
+            .collect::<Vec<_>>();
+        writeln!(
+            report,
+            "| {id} | {} | {} |",
+            cell(titles.get(id).map(|x| x.as_str()).unwrap_or_default()),
+            mitigations.join(", ")
+        )
+        .unwrap();
+    }
+    writeln!(report).unwrap();
+
+    writeln!(report, "## Conclusion\n").unwrap();
+    writeln!(report, "*To be completed by the validation lead.*\n").unwrap();
+
+    writeln!(report, "## Signatures\n").unwrap();
+    writeln!(report, "| Role | Name | Signature | Date |").unwrap();
+    writeln!(report, "|------|------|-----------|------|").unwrap();
+    for role in ["Author", "Reviewer", "Approver"] {
+        writeln!(report, "| {role} | | | |").unwrap();
+    }
+
+    report
+}
+
+/// Splits `text` into text and the terms of `tooltips` in it, with their tooltip
+fn with_tooltips<'a>(
+    text: &str,
+    tooltips: &IndexMap<String, String>,
+    terms: &Regex,
+) -> Vec<Event<'a>> {
+    let mut events = vec![];
+    let mut last = 0;
+    for found in terms.find_iter(text) {
+        let Some(definition) = tooltips.get(found.as_str()) else {
+            continue;
+        };
+        events.push(Event::Text(text[last..found.start()].to_string().into()));
+        let mut title = String::new();
+        escape_html(&mut title, definition).unwrap();
+        let mut term = String::new();
+        escape_html(&mut term, found.as_str()).unwrap();
+        events.push(Event::Html(
+            format!("<abbr title=\"{title}\">{term}</abbr>").into(),
+        ));
+        last = found.end();
+    }
+    events.push(Event::Text(text[last..].to_string().into()));
+    events
+}
+
+/// Renders the markdown `report` as a standalone HTML document, where the terms of `glossary`
+/// have their definition as tooltip
+pub fn to_html(report: &str, glossary: &Glossary) -> String {
+    let tooltips = tooltips(glossary);
+    // longest terms first, so that a term is preferred to the terms it contains
+    let mut terms = tooltips
+        .keys()
+        .map(|x| regex::escape(x))
+        .collect::<Vec<_>>();
+    terms.sort_by_key(|x| std::cmp::Reverse(x.len()));
+    let terms = (!terms.is_empty())
+        .then(|| Regex::new(&format!(r"\b(?:{})\b", terms.join("|"))).ok())
+        .flatten();
+
+    let mut in_code = false;
+    let events = Parser::new_ext(report, Options::ENABLE_TABLES).flat_map(|event| match event {
+        Event::Start(Tag::CodeBlock(_)) => {
+            in_code = true;
+            vec![event]
+        }
+        Event::End(Tag::CodeBlock(_)) => {
+            in_code = false;
+            vec![event]
+        }
+        Event::Text(inner) if !in_code && terms.is_some() => {
+            with_tooltips(&inner, &tooltips, terms.as_ref().unwrap())
+        }
+        event => vec![event],
+    });
+    let mut body = String::new();
+    html::push_html(&mut body, events);
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Validation summary report</title>\n</head>\n<body>\n{body}</body>\n</html>\n"
+    )
+}
@@ -13,15 +13,29 @@ fn dir_name<'a>(iter: impl Iterator<Item = &'a str>) -> String {
     format!("{}", hasher.finish())
 }
 
-fn create_local_project(
-    spec: &str,
-    design: &str,
-    risk: &str,
-    test: &str,
-    user_manual: &str,
-    operator_manual: &str,
-    retirement_plan: &str,
-) -> PathBuf {
+/// The docstring of `step`, without the line break following its opening delimiter, as in Gherkin
+fn docstring(step: &Step) -> String {
+    let docstring = step.docstring.as_deref().unwrap();
+    docstring
+        .strip_prefix('\n')
+        .unwrap_or(docstring)
+        .to_string()
+}
+
+fn create_local_project(w: &World) -> PathBuf {
+    let spec = w.feature.as_str();
+    let design = w.design_specification.as_str();
+    let risk = w.risk_assessment.as_str();
+    let test = w.verification_plan.as_str();
+    let user_manual = w.user_manual.as_str();
+    let operator_manual = w.operator_manual.as_str();
+    let retirement_plan = w.retirement_plan.as_str();
+    let commits = w
+        .commits
+        .iter()
+        .map(|(author, message, files, tag)| format!("{author}:{message}:{files}:{tag:?}"))
+        .collect::<Vec<_>>();
+
     let dir = std::env::temp_dir();
     let dir = dir.join(dir_name(
         [
@@ -33,7 +47,13 @@ fn create_local_project(
             operator_manual,
             retirement_plan,
         ]
-        .into_iter(),
+        .into_iter()
+        .chain(
+            w.files
+                .iter()
+                .flat_map(|(path, content)| [path.as_str(), content.as_str()]),
+        )
+        .chain(commits.iter().map(|commit| commit.as_str())),
     ));
     let _ = std::fs::remove_dir_all(&dir);
     let _ = std::fs::create_dir(&dir);
@@ -76,12 +96,58 @@ fn create_local_project(
     } else {
         std::fs::write(dir.join("verification_plan.md"), "# Verification plan").unwrap();
     }
+    // files declared after the documentation is committed are only written after the commit
+    let mut written = 0;
+    for (day, (author, message, files, tag)) in w.commits.iter().enumerate() {
+        write_files(&dir, &w.files[written..*files]);
+        commit_project(&dir, author, message, day + 1, tag.as_deref());
+        written = *files;
+    }
+    write_files(&dir, &w.files[written..]);
     dir
 }
 
-fn command(path: &Path) -> Command {
+fn write_files(dir: &Path, files: &[(String, String)]) {
+    for (path, content) in files {
+        let path = dir.join(path);
+        let _ = std::fs::create_dir_all(path.parent().unwrap());
+        std::fs::write(path, content).unwrap();
+    }
+}
+
+/// Commits every file of the project `dir` to its git repository (initialized if needed), authored by `author`
+/// on day `day` of January 2024, and tags the commit with `tag`, if any
+fn commit_project(dir: &Path, author: &str, message: &str, day: usize, tag: Option<&str>) {
+    let date = format!("2024-01-{day:02}T12:00:00+00:00");
+    let git = |args: &[&str]| {
+        let status = std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .env("GIT_AUTHOR_NAME", author)
+            .env("GIT_AUTHOR_EMAIL", "author@example.com")
+            .env("GIT_COMMITTER_NAME", author)
+            .env("GIT_COMMITTER_EMAIL", "author@example.com")
+            .env("GIT_AUTHOR_DATE", &date)
+            .env("GIT_COMMITTER_DATE", &date)
+            .status()
+            .unwrap();
+        assert!(status.success());
+    };
+    git(&["init", "--quiet"]);
+    git(&["add", "."]);
+    git(&["commit", "--quiet", "--no-gpg-sign", "-m", message]);
+    if let Some(tag) = tag {
+        git(&["tag", tag]);
+    }
+}
+
+fn command(w: &World) -> Command {
     let mut cmd = Command::cargo_bin("quality").unwrap();
-    cmd.arg("--path").arg(path);
+    cmd.arg("--path").arg(&w.path);
+    cmd.args(&w.args);
+    if w.has_spec {
+        cmd.current_dir(&w.path);
+    }
     cmd
 }
 
@@ -94,8 +160,12 @@ struct World {
     user_manual: String,
     operator_manual: String,
     retirement_plan: String,
+    files: Vec<(String, String)>,
     has_spec: bool,
+    /// author, message, number of files declared before the commit and tag of each commit of the documentation
+    commits: Vec<(String, String, usize, Option<String>)>,
     path: PathBuf,
+    args: Vec<String>,
 }
 
 #[given(expr = "software without a specification")]
@@ -106,43 +176,74 @@ fn software_unspecified(w: &mut World) {
 
 #[given(expr = "the following feature")]
 fn a_feature(w: &mut World, step: &Step) {
-    w.feature = step.docstring.as_ref().unwrap().clone();
+    w.feature = docstring(step);
     w.has_spec = true;
 }
 
 #[given(expr = "the following content in `design_specification.md`")]
 fn a_design(w: &mut World, step: &Step) {
-    w.design_specification = step.docstring.as_ref().unwrap().clone();
+    w.design_specification = docstring(step);
     w.has_spec = true;
 }
 
 #[given(expr = "the following content in `risk_assessment.md`")]
 fn a_risk(w: &mut World, step: &Step) {
-    w.risk_assessment = step.docstring.as_ref().unwrap().clone();
+    w.risk_assessment = docstring(step);
     w.has_spec = true;
 }
 
 #[given(expr = "the following content in `verification_plan.md`")]
 fn a_test(w: &mut World, step: &Step) {
-    w.verification_plan = step.docstring.as_ref().unwrap().clone();
+    w.verification_plan = docstring(step);
     w.has_spec = true;
 }
 
 #[given(expr = "the following content in `user_manual.md`")]
 fn a_user_manual(w: &mut World, step: &Step) {
-    w.user_manual = step.docstring.as_ref().unwrap().clone();
+    w.user_manual = docstring(step);
     w.has_spec = true;
 }
 
 #[given(expr = "the following content in `operator_manual.md`")]
 fn a_operator_manual(w: &mut World, step: &Step) {
-    w.operator_manual = step.docstring.as_ref().unwrap().clone();
+    w.operator_manual = docstring(step);
     w.has_spec = true;
 }
 
 #[given(expr = "the following content in `retirement_plan.md`")]
 fn a_retirement(w: &mut World, step: &Step) {
-    w.retirement_plan = step.docstring.as_ref().unwrap().clone();
+    w.retirement_plan = docstring(step);
+    w.has_spec = true;
+}
+
+#[given(regex = r"^the following file `(.+)`$")]
+fn a_file(w: &mut World, step: &Step, path: String) {
+    w.files.push((path, docstring(step)));
+    w.has_spec = true;
+}
+
+#[given(regex = r"^the documentation is committed to git by `([^`]+)`$")]
+fn committed(w: &mut World, author: String) {
+    w.commits
+        .push((author, "Documentation".to_string(), w.files.len(), None));
+    w.has_spec = true;
+}
+
+#[given(regex = r"^the documentation is committed to git by `([^`]+)` and tagged `([^`]+)`$")]
+fn committed_and_tagged(w: &mut World, author: String, tag: String) {
+    w.commits.push((
+        author,
+        "Documentation".to_string(),
+        w.files.len(),
+        Some(tag),
+    ));
+    w.has_spec = true;
+}
+
+#[given(regex = r"^the documentation is committed to git by `(.+)` with the message$")]
+fn committed_with_message(w: &mut World, step: &Step, author: String) {
+    let message = docstring(step);
+    w.commits.push((author, message, w.files.len(), None));
     w.has_spec = true;
 }
 
@@ -150,30 +251,28 @@ fn a_retirement(w: &mut World, step: &Step) {
 #[when(expr = "we check it")]
 fn check_docs(w: &mut World) {
     w.path = if w.has_spec {
-        create_local_project(
-            &w.feature,
-            &w.design_specification,
-            &w.risk_assessment,
-            &w.verification_plan,
-            &w.user_manual,
-            &w.operator_manual,
-            &w.retirement_plan,
-        )
+        create_local_project(w)
     } else {
         "./not_a_directory".into()
     };
 }
 
+#[when(regex = r"^we check it with `(.*)`$")]
+fn check_docs_with(w: &mut World, args: String) {
+    check_docs(w);
+    w.args = args.split_whitespace().map(|arg| arg.to_string()).collect();
+}
+
 #[then("we get an error of a missing risk assessment file")]
 fn missing_risk(w: &mut World) {
-    command(&w.path).assert().failure().stderr(
+    command(w).assert().failure().stderr(
         predicates::str::contains("ERROR").and(predicates::str::contains("risk_assessment.md")),
     );
 }
 
 #[then("we get an error of a missing design specification")]
 fn missing_design(w: &mut World) {
-    command(&w.path).assert().failure().stderr(
+    command(w).assert().failure().stderr(
         predicates::str::contains("ERROR")
             .and(predicates::str::contains("design_specification.md")),
     );
@@ -181,35 +280,35 @@ fn missing_design(w: &mut World) {
 
 #[then("we get an error of a missing retirement plan")]
 fn missing_retirement(w: &mut World) {
-    command(&w.path).assert().failure().stderr(
+    command(w).assert().failure().stderr(
         predicates::str::contains("ERROR").and(predicates::str::contains("retirement_plan.md")),
     );
 }
 
 #[then("we get an error of a missing verification plan")]
 fn missing_verification(w: &mut World) {
-    command(&w.path).assert().failure().stderr(
+    command(w).assert().failure().stderr(
         predicates::str::contains("ERROR").and(predicates::str::contains("verification_plan.md")),
     );
 }
 
 #[then("we get an error of a missing user manual file")]
 fn missing_user_manual(w: &mut World) {
-    command(&w.path).assert().failure().stderr(
+    command(w).assert().failure().stderr(
         predicates::str::contains("ERROR").and(predicates::str::contains("user_manual.md")),
     );
 }
 
 #[then("we get an error of a missing operator manual file")]
 fn missing_operator_manual(w: &mut World) {
-    command(&w.path).assert().failure().stderr(
+    command(w).assert().failure().stderr(
         predicates::str::contains("ERROR").and(predicates::str::contains("operator_manual.md")),
     );
 }
 
 #[then("we get an error of an incorrect risk assessment")]
 fn check_fails_identifier_risk(w: &mut World) {
-    command(&w.path)
+    command(w)
         .assert()
         .failure()
         .stderr(
@@ -221,7 +320,7 @@ fn check_fails_identifier_risk(w: &mut World) {
 
 #[then("we get an error of an incorrect design specification")]
 fn check_fails_identifier_design(w: &mut World) {
-    command(&w.path)
+    command(w)
         .assert()
         .failure()
         .stderr(
@@ -233,7 +332,7 @@ fn check_fails_identifier_design(w: &mut World) {
 
 #[then("we get an error of an incorrect header in design specification")]
 fn then_missing_header_in_design(w: &mut World) {
-    command(&w.path)
+    command(w)
         .assert()
         .failure()
         .stderr(
@@ -245,7 +344,7 @@ fn then_missing_header_in_design(w: &mut World) {
 
 #[then("we get an error of an incorrect verification plan")]
 fn check_fails_identifier_verification(w: &mut World) {
-    command(&w.path)
+    command(w)
         .assert()
         .failure()
         .stderr(
@@ -257,7 +356,7 @@ fn check_fails_identifier_verification(w: &mut World) {
 
 #[then("we get an error of an incorrect user manual")]
 fn check_fails_identifier_user_manual(w: &mut World) {
-    command(&w.path)
+    command(w)
         .assert()
         .failure()
         .stderr(
@@ -269,7 +368,7 @@ fn check_fails_identifier_user_manual(w: &mut World) {
 
 #[then("we get an error of an incorrect operator manual")]
 fn check_fails_identifier_operator_manual(w: &mut World) {
-    command(&w.path)
+    command(w)
         .assert()
         .failure()
         .stderr(
@@ -281,7 +380,7 @@ fn check_fails_identifier_operator_manual(w: &mut World) {
 
 #[then("we get an error of an incorrect retirement plan")]
 fn check_fails_identifier_retirement_plan(w: &mut World) {
-    command(&w.path)
+    command(w)
         .assert()
         .failure()
         .stderr(
@@ -293,7 +392,7 @@ fn check_fails_identifier_retirement_plan(w: &mut World) {
 
 #[then("we get an error of a missing specification")]
 fn check_fails_specification(w: &mut World) {
-    command(&w.path)
+    command(w)
         .assert()
         .failure()
         .stderr(predicates::str::contains("/features"));
@@ -301,7 +400,7 @@ fn check_fails_specification(w: &mut World) {
 
 #[then("we get an error regarding a wrong identifier")]
 fn check_fails_identifier(w: &mut World) {
-    command(&w.path)
+    command(w)
         .assert()
         .failure()
         .stderr(
@@ -313,7 +412,7 @@ fn check_fails_identifier(w: &mut World) {
 
 #[then("we get an error regarding a wrong trace in risks")]
 fn check_fails_identifier_trace_risk(w: &mut World) {
-    command(&w.path)
+    command(w)
         .assert()
         .failure()
         .stderr(
@@ -325,7 +424,7 @@ fn check_fails_identifier_trace_risk(w: &mut World) {
 
 #[then("we get an error regarding a wrong trace in design")]
 fn check_fails_identifier_trace_design(w: &mut World) {
-    command(&w.path)
+    command(w)
         .assert()
         .failure()
         .stderr(
@@ -337,7 +436,7 @@ fn check_fails_identifier_trace_design(w: &mut World) {
 
 #[then("we get an error regarding a wrong trace in verification plan")]
 fn check_fails_identifier_trace_verification(w: &mut World) {
-    command(&w.path)
+    command(w)
         .assert()
         .failure()
         .stderr(
@@ -349,7 +448,7 @@ fn check_fails_identifier_trace_verification(w: &mut World) {
 
 #[then("we get an error regarding a wrong trace in user manual")]
 fn check_fails_identifier_trace_manual(w: &mut World) {
-    command(&w.path)
+    command(w)
         .assert()
         .failure()
         .stderr(
@@ -361,7 +460,7 @@ fn check_fails_identifier_trace_manual(w: &mut World) {
 
 #[then("we get an error regarding wrong Gherkin")]
 fn check_fails_gherkin(w: &mut World) {
-    command(&w.path)
+    command(w)
         .assert()
         .failure()
         .stderr(predicates::str::contains("ERROR"));
@@ -369,12 +468,90 @@ fn check_fails_gherkin(w: &mut World) {
 
 #[then("we get no error")]
 fn check_ok(w: &mut World) {
-    command(&w.path).assert().success();
+    command(w).assert().success();
+}
+
+#[when(regex = r"^we save its output to `(.+)`$")]
+fn save_output(w: &mut World, path: String) {
+    let output = command(w).output().unwrap();
+    assert!(output.status.success());
+    std::fs::write(w.path.join(path), output.stdout).unwrap();
+}
+
+#[when(regex = r"^we check it again with `(.*)`$")]
+fn check_again_with(w: &mut World, args: String) {
+    w.args = args.split_whitespace().map(|arg| arg.to_string()).collect();
+}
+
+#[then("we get an output containing")]
+fn check_output(w: &mut World, step: &Step) {
+    command(w)
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            step.docstring.as_ref().unwrap().trim(),
+        ));
+}
+
+#[then("we get an output not containing")]
+fn check_output_not(w: &mut World, step: &Step) {
+    command(w)
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(step.docstring.as_ref().unwrap().trim()).not());
+}
+
+#[then("we get a failure with an output containing")]
+fn check_failed_output(w: &mut World, step: &Step) {
+    command(w)
+        .assert()
+        .failure()
+        .stdout(predicates::str::contains(
+            step.docstring.as_ref().unwrap().trim(),
+        ));
+}
+
+#[then("we get an error containing")]
+fn check_error(w: &mut World, step: &Step) {
+    command(w)
+        .assert()
+        .failure()
+        .stderr(
+            predicates::str::contains("ERROR").and(predicates::str::contains(
+                step.docstring.as_ref().unwrap().trim(),
+            )),
+        );
+}
+
+#[then("we get a warning containing")]
+fn check_warning(w: &mut World, step: &Step) {
+    command(w)
+        .assert()
+        .success()
+        .stderr(
+            predicates::str::contains("WARNING").and(predicates::str::contains(
+                step.docstring.as_ref().unwrap().trim(),
+            )),
+        );
+}
+
+#[then(regex = r"^the file `(.+)` can only be read by its owner$")]
+fn check_owner_only(w: &mut World, path: String) {
+    command(w).assert().success();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(w.path.join(path))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o077, 0, "{mode:o} allows group or others");
+    }
 }
 
 #[then("we get the following JSON")]
 fn check_json(w: &mut World, step: &Step) {
-    let assert = command(&w.path).assert().success();
+    let assert = command(w).assert().success();
     let output = assert.get_output();
     assert_eq!(
         serde_json::from_slice::<serde_json::Value>(&output.stdout).unwrap(),